@@ -0,0 +1,179 @@
+use crate::chamber::Chamber;
+use serde::Serialize;
+
+/// The step of the legislative process an action code belongs to. Each
+/// category maps to at most one [`Stage`](crate::status::Stage), so the
+/// stage of an action code follows from its entry here.
+#[derive(Debug, Serialize, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ActionCategory {
+    Introduction,
+    Referral,
+    /// Hearings, markups and other committee work short of a report
+    Committee,
+    Reported,
+    Calendar,
+    /// Floor actions other than passage, including failed votes
+    Floor,
+    Passage,
+    ResolvingDifferences,
+    /// Presentment to and signature by the President
+    President,
+    Veto,
+    Law,
+}
+
+/// A Library of Congress action code and what it means
+///
+/// The dictionary covers the Library of Congress step codes, the executive
+/// codes and the common House floor codes; codes outside it are left for
+/// callers to interpret.
+#[derive(Debug, Serialize, Copy, Clone, PartialEq, Eq)]
+pub struct ActionCodeInfo {
+    pub code: &'static str,
+    pub description: &'static str,
+    pub chamber: Option<Chamber>,
+    pub category: ActionCategory,
+}
+
+impl ActionCodeInfo {
+    /// Looks up an action code such as `H11100` or `E40000`
+    pub fn lookup(code: &str) -> Option<&'static ActionCodeInfo> {
+        ACTION_CODES.iter().find(|info| info.code == code)
+    }
+
+    /// Every action code in the dictionary
+    pub fn all() -> &'static [ActionCodeInfo] {
+        ACTION_CODES
+    }
+}
+
+const fn info(
+    code: &'static str,
+    description: &'static str,
+    chamber: Option<Chamber>,
+    category: ActionCategory,
+) -> ActionCodeInfo {
+    ActionCodeInfo {
+        code,
+        description,
+        chamber,
+        category,
+    }
+}
+
+const HOUSE: Option<Chamber> = Some(Chamber::House);
+const SENATE: Option<Chamber> = Some(Chamber::Senate);
+
+use ActionCategory::*;
+
+static ACTION_CODES: &[ActionCodeInfo] = &[
+    // Library of Congress codes
+    info("1000", "Introduced in House", HOUSE, Introduction),
+    info("2000", "Referred to House committee", HOUSE, Referral),
+    info(
+        "4000",
+        "House committee/subcommittee actions",
+        HOUSE,
+        Committee,
+    ),
+    info("5000", "Reported to House", HOUSE, Reported),
+    info("8000", "Passed/agreed to in House", HOUSE, Passage),
+    info(
+        "9000",
+        "Failed of passage/not agreed to in House",
+        HOUSE,
+        Floor,
+    ),
+    info("10000", "Introduced in Senate", SENATE, Introduction),
+    info("11000", "Referred to Senate committee", SENATE, Referral),
+    info("14000", "Reported to Senate", SENATE, Reported),
+    info("17000", "Passed/agreed to in Senate", SENATE, Passage),
+    info(
+        "18000",
+        "Failed of passage/not agreed to in Senate",
+        SENATE,
+        Floor,
+    ),
+    info(
+        "19500",
+        "Resolving differences -- Senate actions",
+        SENATE,
+        ResolvingDifferences,
+    ),
+    info(
+        "20500",
+        "Resolving differences -- House actions",
+        HOUSE,
+        ResolvingDifferences,
+    ),
+    info(
+        "20800",
+        "Conference report filed",
+        None,
+        ResolvingDifferences,
+    ),
+    info(
+        "20900",
+        "Conference report agreed to",
+        None,
+        ResolvingDifferences,
+    ),
+    info("28000", "Presented to President", None, President),
+    info("29000", "Signed by President", None, President),
+    info("31000", "Vetoed by President", None, Veto),
+    info("36000", "Became Public Law", None, Law),
+    info("37000", "Became Private Law", None, Law),
+    // House floor and committee codes
+    info("Intro-H", "Introduced in House", HOUSE, Introduction),
+    info("H11100", "Referred to House committee", HOUSE, Referral),
+    info(
+        "H11200",
+        "Referred sequentially to House committee",
+        HOUSE,
+        Referral,
+    ),
+    info(
+        "H12200",
+        "Reported to House with amendment",
+        HOUSE,
+        Reported,
+    ),
+    info(
+        "H12300",
+        "Reported to House without amendment",
+        HOUSE,
+        Reported,
+    ),
+    info("H12410", "Placed on Union Calendar", HOUSE, Calendar),
+    info("H12420", "Placed on House Calendar", HOUSE, Calendar),
+    info("H14000", "Received in the House", HOUSE, Calendar),
+    info("H15000", "Held at the desk", HOUSE, Calendar),
+    info(
+        "H1L210",
+        "Rule providing for consideration reported to House",
+        HOUSE,
+        Floor,
+    ),
+    info(
+        "H1L220",
+        "Rule providing for consideration agreed to",
+        HOUSE,
+        Floor,
+    ),
+    info("H30000", "Considered in House", HOUSE, Floor),
+    info("H30300", "Motion to suspend the rules", HOUSE, Floor),
+    info("H8D000", "Debate in House", HOUSE, Floor),
+    info("H37100", "Passed House by recorded vote", HOUSE, Passage),
+    info("H37300", "Passed House by voice vote", HOUSE, Passage),
+    info(
+        "H38310",
+        "Motion to reconsider laid on the table",
+        HOUSE,
+        Floor,
+    ),
+    // Executive codes
+    info("E20000", "Presented to President", None, President),
+    info("E30000", "Signed by President", None, President),
+    info("E40000", "Became Public Law", None, Law),
+    info("E50000", "Vetoed by President", None, Veto),
+];
@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
 pub enum AmendmentType {
-    #[serde(rename(deserialize = "HAMDT"), alias = "hamdt")]
+    #[serde(rename = "HAMDT", alias = "hamdt")]
     House,
-    #[serde(rename(deserialize = "SAMDT"), alias = "samdt")]
+    #[serde(rename = "SAMDT", alias = "samdt")]
     Senate,
 }
 
@@ -0,0 +1,94 @@
+use crate::{error::MissingApiKeySnafu, Result};
+use std::{
+    fmt::Debug,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The environment variable read by [`ClientBuilder::from_env`](crate::ClientBuilder::from_env)
+pub const API_KEY_VAR: &str = "CONGRESS_API_KEY";
+
+/// The header the key is sent in
+pub(crate) const API_KEY_HEADER: &str = "X-Api-Key";
+
+/// An api.data.gov key. Its `Debug` output never contains the key itself.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        ApiKey(key.into())
+    }
+
+    /// The key itself, for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ApiKey(REDACTED)")
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(key: String) -> Self {
+        ApiKey(key)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(key: &str) -> Self {
+        ApiKey(key.to_string())
+    }
+}
+
+/// Parses the comma separated keys in [`API_KEY_VAR`]
+pub(crate) fn keys_from_env() -> Result<Vec<ApiKey>> {
+    let value = std::env::var(API_KEY_VAR).ok();
+    let keys: Vec<ApiKey> = value
+        .iter()
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(ApiKey::from)
+        .collect();
+    snafu::ensure!(!keys.is_empty(), MissingApiKeySnafu { var: API_KEY_VAR });
+    Ok(keys)
+}
+
+/// The keys a client rotates through when one hits its hourly quota
+#[derive(Debug)]
+pub(crate) struct KeyRing {
+    keys: Vec<ApiKey>,
+    current: AtomicUsize,
+}
+
+impl KeyRing {
+    /// `keys` must not be empty
+    pub(crate) fn new(keys: Vec<ApiKey>) -> Self {
+        assert!(!keys.is_empty(), "a key ring needs at least one key");
+        KeyRing {
+            keys,
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// The key in use and its index, to pass to [`KeyRing::rotate`]
+    pub(crate) fn current(&self) -> (usize, &ApiKey) {
+        let index = self.current.load(Ordering::Relaxed);
+        (index, &self.keys[index])
+    }
+
+    /// Moves on from the key at `index`, unless a concurrent request already has
+    pub(crate) fn rotate(&self, index: usize) {
+        let next = (index + 1) % self.keys.len();
+        let _ = self
+            .current
+            .compare_exchange(index, next, Ordering::Relaxed, Ordering::Relaxed);
+    }
+}
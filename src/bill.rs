@@ -1,12 +1,25 @@
-use crate::{bill_type::BillType, chamber::Chamber, latest_action::LatestAction, Client, Result};
+use crate::{
+    bill_id::BillId,
+    bill_type::BillType,
+    chamber::Chamber,
+    error::InvalidBillNumberSnafu,
+    latest_action::LatestAction,
+    parameters::PageParameters,
+    party::Party,
+    state::{District, State},
+    status::Status,
+    Client, Result,
+};
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
 use url::Url;
 
 use self::actions::ActionsHandler;
 use self::amendments::AmendmentsHandler;
 use self::committees::CommitteesHandler;
 use self::cosponsors::CosponsorsHandler;
+use self::diff::BillDiff;
 use self::related_bills::RelatedBillsHandler;
 use self::subjects::SubjectsHandler;
 use self::summaries::SummariesHandler;
@@ -17,114 +30,131 @@ pub mod actions;
 pub mod amendments;
 pub mod committees;
 pub mod cosponsors;
+pub mod diff;
 pub mod related_bills;
 pub mod subjects;
 pub mod summaries;
 pub mod text;
 pub mod titles;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Actions {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Amendments {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct CboCostEstimate {
     pub description: String,
     pub pub_date: Option<DateTime<Utc>>,
     pub title: String,
     pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct CommitteeReport {
     pub citation: String,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Committees {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Cosponsors {
+    /// Current cosponsors
     pub count: u32,
     pub count_including_withdrawn_cosponsors: Option<u32>,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Law {
     pub number: String,
     #[serde(rename = "type")]
     pub type_: String,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct PolicyArea {
     pub name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RelatedBills {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Sponsor {
     pub bioguide_id: String,
-    pub district: Option<u32>,
+    /// Unset for senators
+    pub district: Option<District>,
     pub first_name: String,
     pub full_name: String,
     pub is_by_request: String,
     pub last_name: String,
     pub middle_name: Option<String>,
-    pub party: String,
-    pub state: String,
+    pub party: Party,
+    pub state: State,
     pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Subjects {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Summaries {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TextVersions {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Titles {
     pub count: u32,
     pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Bill {
     pub actions: Actions,
@@ -147,11 +177,32 @@ pub struct Bill {
     pub summaries: Option<Summaries>,
     pub text_versions: Option<TextVersions>,
     pub title: String,
+    #[serde(rename = "type")]
+    pub bill_type: BillType,
     pub update_date: DateTime<Utc>,
     pub update_date_including_text: DateTime<Utc>,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+impl Bill {
+    /// Fails if the API returned a number that isn't numeric
+    pub fn id(&self) -> Result<BillId> {
+        let number = self.number.parse().context(InvalidBillNumberSnafu {
+            number: &self.number,
+        })?;
+        Ok(BillId::new(self.congress, self.bill_type, number))
+    }
+
+    /// What changed between this snapshot and a later one of the same bill
+    pub fn diff(&self, other: &Bill) -> BillDiff {
+        BillDiff::new(self, other)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct BillResponse {
     pub bill: Bill,
@@ -180,50 +231,95 @@ impl<'client> BillHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<BillResponse> {
-        let path = format!(
+    pub fn id(&self) -> BillId {
+        BillId::new(self.congress, self.bill_type, self.bill_number)
+    }
+
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}",
             congress = self.congress,
             bill_type = self.bill_type,
             bill_number = self.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "bill",
+            skip_all,
+            fields(
+                congress = self.congress,
+                bill_type = %self.bill_type,
+                bill_number = self.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<BillResponse> {
+        self.client.get(&self.path(), None).await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.client.get(&self.path(), None).await
+    }
+
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.client.get_xml(&self.path(), None).await
+    }
+
+    /// Fetches every page of the bill's actions and derives its status
+    pub async fn status(&self) -> Result<Option<Status>> {
+        let mut page = self.actions().limit(250).send().await?;
+        let mut actions = vec![];
+        loop {
+            let next = self.client.next(&page).await?;
+            actions.extend(page.actions);
+            match next {
+                Some(next) => page = next,
+                None => break,
+            }
+        }
 
-        self.client.get(&path, None::<&()>).await
+        Ok(Status::from_actions(&actions))
     }
 
-    pub fn actions(&self) -> ActionsHandler {
+    pub fn actions(&self) -> ActionsHandler<'_> {
         ActionsHandler::new(self)
     }
 
-    pub fn amendments(&self) -> AmendmentsHandler {
+    pub fn amendments(&self) -> AmendmentsHandler<'_> {
         AmendmentsHandler::new(self)
     }
 
-    pub fn committees(&self) -> CommitteesHandler {
+    pub fn committees(&self) -> CommitteesHandler<'_> {
         CommitteesHandler::new(self)
     }
 
-    pub fn cosponsors(&self) -> CosponsorsHandler {
+    pub fn cosponsors(&self) -> CosponsorsHandler<'_> {
         CosponsorsHandler::new(self)
     }
 
-    pub fn related_bills(&self) -> RelatedBillsHandler {
+    pub fn related_bills(&self) -> RelatedBillsHandler<'_> {
         RelatedBillsHandler::new(self)
     }
 
-    pub fn subjects(&self) -> SubjectsHandler {
+    pub fn subjects(&self) -> SubjectsHandler<'_> {
         SubjectsHandler::new(self)
     }
 
-    pub fn summaries(&self) -> SummariesHandler {
+    pub fn summaries(&self) -> SummariesHandler<'_> {
         SummariesHandler::new(self)
     }
 
-    pub fn text(&self) -> TextHandler {
+    pub fn text(&self) -> TextHandler<'_> {
         TextHandler::new(self)
     }
 
-    pub fn titles(&self) -> TitlesHandler {
+    pub fn titles(&self) -> TitlesHandler<'_> {
         TitlesHandler::new(self)
     }
 }
@@ -1,19 +1,71 @@
-use super::BillHandler;
+use super::{committees::CommitteeRef, BillHandler};
 use crate::{
+    action_code::ActionCodeInfo,
+    chamber::Chamber,
     pagination::{PagedResponse, Pagination},
     parameters::{HasParameters, PageParameters, Parameters},
+    status::{Stage, Status},
+    votes::RecordedVote,
     Result,
 };
 use chrono::NaiveDate;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+/// The system that recorded an action
+///
+/// Codes this crate doesn't know about are kept in [`SourceSystemCode::Other`].
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+#[serde(from = "u32", into = "u32")]
+pub enum SourceSystemCode {
+    Senate,
+    HouseCommitteeActions,
+    HouseFloorActions,
+    LibraryOfCongress,
+    Other(u32),
+}
+
+impl From<u32> for SourceSystemCode {
+    fn from(code: u32) -> Self {
+        match code {
+            0 => Self::Senate,
+            1 => Self::HouseCommitteeActions,
+            2 => Self::HouseFloorActions,
+            9 => Self::LibraryOfCongress,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<SourceSystemCode> for u32 {
+    fn from(code: SourceSystemCode) -> Self {
+        match code {
+            SourceSystemCode::Senate => 0,
+            SourceSystemCode::HouseCommitteeActions => 1,
+            SourceSystemCode::HouseFloorActions => 2,
+            SourceSystemCode::LibraryOfCongress => 9,
+            SourceSystemCode::Other(code) => code,
+        }
+    }
+}
+
+impl SourceSystemCode {
+    /// The chamber that recorded the action, if it was recorded by one
+    pub fn chamber(&self) -> Option<Chamber> {
+        match self {
+            Self::Senate => Some(Chamber::Senate),
+            Self::HouseCommitteeActions | Self::HouseFloorActions => Some(Chamber::House),
+            Self::LibraryOfCongress | Self::Other(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct SourceSystem {
-    pub code: Option<u32>,
+    pub code: Option<SourceSystemCode>,
     pub name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
 pub enum ActionType {
     BecameLaw,
     President,
@@ -23,18 +75,56 @@ pub enum ActionType {
     Floor,
 }
 
-#[derive(Debug, Deserialize)]
+/// The calendar a bill was placed on, e.g. `U00012` for the twelfth item on the Union Calendar
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarNumber {
+    pub calendar: String,
+}
+
+impl CalendarNumber {
+    /// The number of the bill on its calendar
+    pub fn number(&self) -> Option<u32> {
+        self.calendar
+            .trim_start_matches(|c: char| !c.is_ascii_digit())
+            .parse()
+            .ok()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Action {
     pub action_code: Option<String>,
     pub action_date: NaiveDate,
+    pub calendar_number: Option<CalendarNumber>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The committees the action refers to, such as the one a bill was referred to
+    pub committees: Vec<CommitteeRef>,
     pub source_system: SourceSystem,
     pub text: String,
     #[serde(rename = "type")]
     pub action_type: ActionType,
+    pub recorded_votes: Option<Vec<RecordedVote>>,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+impl Action {
+    /// The dictionary entry for the action code, if the action has a known one
+    pub fn code_info(&self) -> Option<&'static ActionCodeInfo> {
+        self.action_code.as_deref().and_then(ActionCodeInfo::lookup)
+    }
+
+    /// The legislative stage this action moves a bill into, if any
+    pub fn stage(&self) -> Option<Stage> {
+        Stage::from_action(self)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct ActionsResponse {
     pub actions: Vec<Action>,
     pagination: Pagination,
@@ -51,6 +141,41 @@ impl PagedResponse<Action> for ActionsResponse {
     }
 }
 
+impl ActionsResponse {
+    /// Actions taken in committee
+    pub fn committee_actions(&self) -> impl Iterator<Item = &Action> {
+        self.of_type(ActionType::Committee)
+    }
+
+    /// Actions taken on the House or Senate floor
+    pub fn floor_actions(&self) -> impl Iterator<Item = &Action> {
+        self.of_type(ActionType::Floor)
+    }
+
+    /// Actions placing the bill on a calendar
+    pub fn calendar_actions(&self) -> impl Iterator<Item = &Action> {
+        self.actions.iter().filter(|a| a.calendar_number.is_some())
+    }
+
+    /// Actions involving the committee with the given system code, e.g. `hsbu00`
+    pub fn by_committee<'a>(&'a self, system_code: &'a str) -> impl Iterator<Item = &'a Action> {
+        self.actions
+            .iter()
+            .filter(move |a| a.committees.iter().any(|c| c.system_code == system_code))
+    }
+
+    pub fn of_type(&self, action_type: ActionType) -> impl Iterator<Item = &Action> {
+        self.actions
+            .iter()
+            .filter(move |a| a.action_type == action_type)
+    }
+
+    /// Derives the bill's status from the actions on this page
+    pub fn status(&self) -> Option<Status> {
+        Status::from_actions(&self.actions)
+    }
+}
+
 impl<'iter> IntoIterator for &'iter ActionsResponse {
     type Item = &'iter Action;
     type IntoIter = std::slice::Iter<'iter, Action>;
@@ -74,15 +199,49 @@ impl<'client> ActionsHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<ActionsResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/actions",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "actions",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<ActionsResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
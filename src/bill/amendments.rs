@@ -7,10 +7,10 @@ use crate::{
     Result,
 };
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Amendment {
     pub congress: u32,
@@ -21,9 +21,13 @@ pub struct Amendment {
     pub amendment_type: AmendmentType,
     pub update_date: DateTime<Utc>,
     pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AmendmentsResponse {
     pub amendments: Vec<Amendment>,
@@ -45,15 +49,49 @@ impl<'client> AmendmentsHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<AmendmentsResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/amendments",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "amendments",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<AmendmentsResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
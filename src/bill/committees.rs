@@ -6,39 +6,59 @@ use crate::{
     Result,
 };
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Activity {
     pub date: DateTime<Utc>,
     pub name: String,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub enum CommitteeType {
     Standing,
 }
 
-#[derive(Debug, Deserialize)]
+/// The fields that identify a committee, shared by [`Committee`] and the
+/// committees an [`Action`](super::actions::Action) refers to
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitteeRef {
+    pub name: String,
+    pub system_code: String,
+    pub url: Url,
+}
+
+/// A committee and what it did with the bill
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Committee {
     pub activities: Vec<Activity>,
     pub chamber: Chamber,
-    pub name: String,
-    pub system_code: String,
+    #[serde(flatten)]
+    pub committee: CommitteeRef,
     #[serde(rename = "type")]
     pub committee_type: CommitteeType,
-    pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CommitteesResponse {
     pub committees: Vec<Committee>,
     pagination: Pagination,
 }
 
+crate::pagination::macros::paged_iterator!(CommitteesResponse, Committee, committees);
+
 #[derive(Debug)]
 pub struct CommitteesHandler<'client> {
     handler: &'client BillHandler<'client>,
@@ -53,15 +73,49 @@ impl<'client> CommitteesHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<CommitteesResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/committees",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "committees",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<CommitteesResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
@@ -2,35 +2,134 @@ use super::BillHandler;
 use crate::{
     pagination::Pagination,
     parameters::{HasParameters, PageParameters, Parameters},
+    party::Party,
+    state::{District, State},
     Result,
 };
 use chrono::NaiveDate;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use url::Url;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Cosponsor {
     pub bioguide_id: String,
-    pub district: u32,
+    /// Unset for senators
+    pub district: Option<District>,
     pub first_name: String,
     pub full_name: String,
-    pub is_originial_cosponsor: bool,
+    /// Whether they cosponsored the bill when it was introduced
+    pub is_original_cosponsor: bool,
     pub last_name: String,
-    pub middle_name: String,
-    pub party: String,
+    pub middle_name: Option<String>,
+    pub party: Party,
     pub sponsorship_date: NaiveDate,
-    pub state: String,
+    /// Withdrawn cosponsors stay listed with the date they withdrew
+    pub sponsorship_withdrawn_date: Option<NaiveDate>,
+    pub state: State,
     pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CosponsorsResponse {
     pub cosponsors: Vec<Cosponsor>,
     pagination: Pagination,
 }
 
+crate::pagination::macros::paged_iterator!(CosponsorsResponse, Cosponsor, cosponsors);
+
+impl Cosponsor {
+    pub fn is_withdrawn(&self) -> bool {
+        self.sponsorship_withdrawn_date.is_some()
+    }
+}
+
+/// The number of cosponsors on a given date
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelinePoint {
+    pub date: NaiveDate,
+    pub cosponsors: u32,
+}
+
+/// Aggregates over the cosponsors in this response. A single page only holds
+/// up to 250 cosponsors, so fetch with [`CosponsorsHandler::send_all`] for the
+/// aggregates to cover every cosponsor of the bill.
+impl CosponsorsResponse {
+    /// Cosponsors who haven't withdrawn
+    pub fn current(&self) -> impl Iterator<Item = &Cosponsor> {
+        self.cosponsors.iter().filter(|c| !c.is_withdrawn())
+    }
+
+    pub fn withdrawn(&self) -> impl Iterator<Item = &Cosponsor> {
+        self.cosponsors.iter().filter(|c| c.is_withdrawn())
+    }
+
+    /// Current cosponsors who signed on when the bill was introduced
+    pub fn original(&self) -> impl Iterator<Item = &Cosponsor> {
+        self.current().filter(|c| c.is_original_cosponsor)
+    }
+
+    /// Current cosponsors who signed on after the bill was introduced
+    pub fn later(&self) -> impl Iterator<Item = &Cosponsor> {
+        self.current().filter(|c| !c.is_original_cosponsor)
+    }
+
+    pub fn count_by_party(&self) -> BTreeMap<Party, u32> {
+        count(self.current().map(|c| c.party.clone()))
+    }
+
+    pub fn count_by_state(&self) -> BTreeMap<State, u32> {
+        count(self.current().map(|c| c.state.clone()))
+    }
+
+    /// The share of current cosponsors outside the sponsor's party, or `None`
+    /// without any
+    pub fn bipartisan_ratio(&self, sponsor_party: &Party) -> Option<f64> {
+        let (total, other) = self.current().fold((0, 0), |(total, other), c| {
+            (total + 1, other + u32::from(&c.party != sponsor_party))
+        });
+        (total > 0).then(|| f64::from(other) / f64::from(total))
+    }
+
+    /// The number of cosponsors after each date someone signed on or withdrew,
+    /// oldest first
+    pub fn timeline(&self) -> Vec<TimelinePoint> {
+        let mut changes: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+        for cosponsor in &self.cosponsors {
+            *changes.entry(cosponsor.sponsorship_date).or_default() += 1;
+            if let Some(date) = cosponsor.sponsorship_withdrawn_date {
+                *changes.entry(date).or_default() -= 1;
+            }
+        }
+        let mut cosponsors = 0;
+        changes
+            .into_iter()
+            .map(|(date, change)| {
+                cosponsors += change;
+                TimelinePoint {
+                    date,
+                    cosponsors: cosponsors.try_into().unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn count<K: Ord>(keys: impl Iterator<Item = K>) -> BTreeMap<K, u32> {
+    let mut counts = BTreeMap::new();
+    for key in keys {
+        *counts.entry(key).or_default() += 1;
+    }
+    counts
+}
+
 #[derive(Debug)]
 pub struct CosponsorsHandler<'client> {
     handler: &'client BillHandler<'client>,
@@ -45,15 +144,63 @@ impl<'client> CosponsorsHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<CosponsorsResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/cosponsors",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "cosponsors",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<CosponsorsResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// Every cosponsor of the bill in one response, following the pagination
+    /// links from the first page
+    pub async fn send_all(&self) -> Result<CosponsorsResponse> {
+        let client = self.handler.client;
+        let mut response = self.send().await?;
+        let mut page = client.next(&response).await?;
+        while let Some(current) = page {
+            page = client.next(&current).await?;
+            response.cosponsors.extend(current.cosponsors);
+        }
+        response.pagination.next = None;
+        Ok(response)
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
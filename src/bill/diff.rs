@@ -0,0 +1,111 @@
+use super::{Bill, CboCostEstimate, CommitteeReport, Law};
+use crate::latest_action::LatestAction;
+use serde::{Deserialize, Serialize};
+
+/// The old and new value of a field that changed
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    fn between(old: T, new: T) -> Option<Self> {
+        (old != new).then_some(Change { old, new })
+    }
+}
+
+/// Field level differences between two snapshots of a bill
+///
+/// Unchanged fields are `None` or empty and are left out when serialized, so
+/// a diff can be written to an audit log as is. Laws, CBO cost estimates and
+/// committee reports only ever accumulate, so only the new ones are listed.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BillDiff {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Change<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_action: Option<Change<LatestAction>>,
+    /// The name of the policy area
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_area: Option<Change<Option<String>>>,
+    /// Counts current cosponsors, not those who withdrew
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cosponsor_count: Option<Change<u32>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new_laws: Vec<Law>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new_cbo_cost_estimates: Vec<CboCostEstimate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new_committee_reports: Vec<CommitteeReport>,
+}
+
+impl BillDiff {
+    pub fn new(old: &Bill, new: &Bill) -> Self {
+        BillDiff {
+            title: Change::between(old.title.clone(), new.title.clone()),
+            latest_action: Change::between(old.latest_action.clone(), new.latest_action.clone()),
+            policy_area: Change::between(policy_area(old), policy_area(new)),
+            cosponsor_count: Change::between(cosponsor_count(old), cosponsor_count(new)),
+            new_laws: added(&old.laws, &new.laws, |law| &law.number),
+            new_cbo_cost_estimates: added(&old.cbo_cost_estimates, &new.cbo_cost_estimates, |e| {
+                &e.url
+            }),
+            new_committee_reports: added(&old.committee_reports, &new.committee_reports, |r| {
+                &r.citation
+            }),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == BillDiff::default()
+    }
+
+    /// The names of the fields that changed, as they are serialized
+    pub fn changed_fields(&self) -> Vec<&'static str> {
+        [
+            ("title", self.title.is_some()),
+            ("latestAction", self.latest_action.is_some()),
+            ("policyArea", self.policy_area.is_some()),
+            ("cosponsorCount", self.cosponsor_count.is_some()),
+            ("newLaws", !self.new_laws.is_empty()),
+            (
+                "newCboCostEstimates",
+                !self.new_cbo_cost_estimates.is_empty(),
+            ),
+            (
+                "newCommitteeReports",
+                !self.new_committee_reports.is_empty(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(name, changed)| changed.then_some(name))
+        .collect()
+    }
+}
+
+fn policy_area(bill: &Bill) -> Option<String> {
+    bill.policy_area.as_ref().map(|p| p.name.clone())
+}
+
+fn cosponsor_count(bill: &Bill) -> u32 {
+    bill.cosponsors
+        .as_ref()
+        .map(|c| c.count)
+        .unwrap_or_default()
+}
+
+/// The items of `new` whose key isn't in `old`
+fn added<T: Clone, K: PartialEq>(
+    old: &Option<Vec<T>>,
+    new: &Option<Vec<T>>,
+    key: impl Fn(&T) -> &K,
+) -> Vec<T> {
+    let old = old.as_deref().unwrap_or_default();
+    new.iter()
+        .flatten()
+        .filter(|n| !old.iter().any(|o| key(o) == key(n)))
+        .cloned()
+        .collect()
+}
@@ -6,10 +6,10 @@ use crate::{
     parameters::{HasParameters, PageParameters, Parameters},
     Result,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RelationshipDetail {
     pub identified_by: String,
@@ -17,7 +17,7 @@ pub struct RelationshipDetail {
     pub type_: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RelatedBill {
     pub congress: u32,
@@ -28,15 +28,21 @@ pub struct RelatedBill {
     #[serde(rename = "type")]
     pub bill_type: BillType,
     pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RelatedBillsResponse {
     pub related_bills: Vec<RelatedBill>,
     pub(crate) pagination: Pagination,
 }
 
+crate::pagination::macros::paged_iterator!(RelatedBillsResponse, RelatedBill, related_bills);
+
 #[derive(Debug)]
 pub struct RelatedBillsHandler<'client> {
     handler: &'client BillHandler<'client>,
@@ -51,15 +57,49 @@ impl<'client> RelatedBillsHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<RelatedBillsResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/relatedbills",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "related_bills",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<RelatedBillsResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
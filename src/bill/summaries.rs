@@ -2,28 +2,35 @@ use super::BillHandler;
 use crate::{
     pagination::Pagination,
     parameters::{HasParameters, PageParameters, Parameters},
+    version_code::SummaryVersionCode,
     Result,
 };
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Summary {
     pub action_date: NaiveDate,
     pub action_desc: String,
     pub text: String,
     pub update_date: DateTime<Utc>,
-    pub version_code: String,
+    pub version_code: SummaryVersionCode,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SummariesResponse {
     pub summaries: Vec<Summary>,
     pagination: Pagination,
 }
 
+crate::pagination::macros::paged_iterator!(SummariesResponse, Summary, summaries);
+
 #[derive(Debug)]
 pub struct SummariesHandler<'client> {
     handler: &'client BillHandler<'client>,
@@ -38,15 +45,49 @@ impl<'client> SummariesHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<SummariesResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/summaries",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "summaries",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<SummariesResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
@@ -1,36 +1,149 @@
 use super::BillHandler;
 use crate::{
+    error::FormatUnavailableSnafu,
     pagination::Pagination,
     parameters::{HasParameters, PageParameters, Parameters},
-    Result,
+    version_code::TextVersionCode,
+    Client, Result,
 };
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use snafu::OptionExt;
 use url::Url;
 
-#[derive(Debug, Deserialize)]
+pub use self::diff::TextDiff;
+pub use self::document::{BillDocument, Section};
+
+pub mod diff;
+pub mod document;
+
+/// The format a text version is published in
+///
+/// Formats this crate doesn't know about are kept in [`FormatKind::Other`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatKind {
+    /// HTML rendering of the bill text
+    FormattedText,
+    /// PDF rendering of the bill text
+    Pdf,
+    /// Bill DTD or USLM XML
+    FormattedXml,
+    Other(String),
+}
+
+impl FormatKind {
+    /// The name the API uses, e.g. `Formatted Text`
+    pub fn name(&self) -> &str {
+        match self {
+            Self::FormattedText => "Formatted Text",
+            Self::Pdf => "PDF",
+            Self::FormattedXml => "Formatted XML",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for FormatKind {
+    fn from(name: &str) -> Self {
+        match name {
+            "Formatted Text" => Self::FormattedText,
+            "PDF" => Self::Pdf,
+            "Formatted XML" => Self::FormattedXml,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for FormatKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatKind {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+impl Serialize for FormatKind {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Format {
     #[serde(rename = "type")]
-    pub type_: String,
-    url: Url,
+    pub format_type: FormatKind,
+    pub url: Url,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TextVersion {
     pub date: DateTime<Utc>,
     pub formats: Vec<Format>,
-    #[serde(rename = "type")]
-    pub type_: String,
+    #[serde(
+        rename = "type",
+        serialize_with = "crate::version_code::serialize_name"
+    )]
+    pub version_code: TextVersionCode,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TextVersion {
+    /// Returns the format of the given kind, if this version is published in it
+    pub fn format(&self, kind: FormatKind) -> Option<&Format> {
+        self.formats.iter().find(|f| f.format_type == kind)
+    }
+
+    /// Downloads the document for the given format
+    pub async fn download(&self, client: &Client, kind: FormatKind) -> Result<Vec<u8>> {
+        let format = self
+            .format(kind.clone())
+            .context(FormatUnavailableSnafu { kind })?;
+        client.get_document(format.url.clone()).await
+    }
+
+    /// Downloads the XML format of this version and parses it into a [`BillDocument`]
+    pub async fn document(&self, client: &Client) -> Result<BillDocument> {
+        let xml = self.download(client, FormatKind::FormattedXml).await?;
+        BillDocument::parse(&xml)
+    }
+
+    /// Downloads and parses this version and a later one, and compares them provision by provision
+    pub async fn diff(&self, client: &Client, newer: &TextVersion) -> Result<TextDiff> {
+        let old = self.document(client).await?;
+        let new = newer.document(client).await?;
+        Ok(TextDiff::new(&old, &new))
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TextResponse {
     pub text_versions: Vec<TextVersion>,
     pagination: Pagination,
 }
+crate::pagination::macros::paged_iterator!(TextResponse, TextVersion, text_versions);
+
+impl TextResponse {
+    /// Returns the text version with the given code, if it is part of this page
+    pub fn version(&self, code: TextVersionCode) -> Option<&TextVersion> {
+        self.text_versions.iter().find(|v| v.version_code == code)
+    }
+}
 
 #[derive(Debug)]
 pub struct TextHandler<'client> {
@@ -46,15 +159,49 @@ impl<'client> TextHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<TextResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/text",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "text",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<TextResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
@@ -0,0 +1,204 @@
+use super::document::{BillDocument, Section};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// The provision only exists in the newer version
+    Inserted,
+    /// The provision only exists in the older version
+    Deleted,
+    /// The provision exists in both versions but its heading or text changed
+    Modified,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvisionChange {
+    pub kind: ChangeKind,
+    /// Labels of the provision and its enclosing divisions, outermost first
+    pub path: Vec<String>,
+    /// Text of the provision in the older version
+    pub old: Option<String>,
+    /// Text of the provision in the newer version
+    pub new: Option<String>,
+}
+
+/// Provision level differences between two versions of a bill
+///
+/// Divisions are aligned by enumerator and heading together, then by heading
+/// alone and finally by enumerator alone, so a renumbered section with an
+/// unchanged heading is reported as modified rather than as a deletion and an
+/// insertion. Divisions with neither are aligned by their text, then by their
+/// position among the unlabeled divisions around them. Changes are listed in
+/// document order, with deletions placed where the provision used to be.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDiff {
+    pub changes: Vec<ProvisionChange>,
+}
+
+impl TextDiff {
+    pub fn new(old: &BillDocument, new: &BillDocument) -> Self {
+        let mut diff = TextDiff::default();
+        diff.compare(&[], &old.sections, &new.sections);
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn inserted(&self) -> impl Iterator<Item = &ProvisionChange> {
+        self.of_kind(ChangeKind::Inserted)
+    }
+
+    pub fn deleted(&self) -> impl Iterator<Item = &ProvisionChange> {
+        self.of_kind(ChangeKind::Deleted)
+    }
+
+    pub fn modified(&self) -> impl Iterator<Item = &ProvisionChange> {
+        self.of_kind(ChangeKind::Modified)
+    }
+
+    fn of_kind(&self, kind: ChangeKind) -> impl Iterator<Item = &ProvisionChange> {
+        self.changes.iter().filter(move |c| c.kind == kind)
+    }
+
+    /// Renders the changes as a unified diff with one hunk per provision
+    pub fn unified(&self) -> String {
+        let mut out = String::new();
+        for change in &self.changes {
+            let path = change.path.join(" / ");
+            let old = lines(change.old.as_deref());
+            let new = lines(change.new.as_deref());
+            let _ = writeln!(out, "--- {path}\n+++ {path}");
+            let diff = similar::TextDiff::from_lines(&old, &new);
+            for hunk in diff.unified_diff().context_radius(1).iter_hunks() {
+                let _ = write!(out, "{hunk}");
+            }
+        }
+        out
+    }
+
+    fn compare(&mut self, path: &[String], old: &[Section], new: &[Section]) {
+        let mut matched = vec![false; old.len()];
+        let mut pairs = Vec::with_capacity(new.len());
+        let unlabeled_old: Vec<usize> = (0..old.len())
+            .filter(|&i| old[i].label().is_empty())
+            .collect();
+        let mut unlabeled_new = 0;
+
+        for section in new {
+            let mut found = find(old, &matched, section, |s| Some(s.label()))
+                .or_else(|| find(old, &matched, section, |s| s.heading.clone()))
+                .or_else(|| find(old, &matched, section, |s| s.enumerator.clone()));
+            if section.label().is_empty() {
+                found = found
+                    .or_else(|| find(old, &matched, section, unlabeled_text))
+                    .or_else(|| {
+                        unlabeled_old
+                            .get(unlabeled_new)
+                            .copied()
+                            .filter(|&i| !matched[i] && old[i].level == section.level)
+                    });
+                unlabeled_new += 1;
+            }
+            if let Some(i) = found {
+                matched[i] = true;
+            }
+            pairs.push((found, section));
+        }
+
+        // Deletions go just before the first provision matched past them
+        let mut next_old = 0;
+        for (found, section) in pairs {
+            let child = child_path(path, section);
+            match found {
+                Some(i) => {
+                    self.delete_unmatched(path, old, &matched, next_old..i);
+                    next_old = next_old.max(i + 1);
+
+                    let previous = &old[i];
+                    if previous.label() != section.label() || previous.text != section.text {
+                        self.changes.push(ProvisionChange {
+                            kind: ChangeKind::Modified,
+                            path: child.clone(),
+                            old: Some(own_text(previous)),
+                            new: Some(own_text(section)),
+                        });
+                    }
+                    self.compare(&child, &previous.children, &section.children);
+                }
+                None => self.changes.push(ProvisionChange {
+                    kind: ChangeKind::Inserted,
+                    path: child,
+                    old: None,
+                    new: Some(section.full_text()),
+                }),
+            }
+        }
+        self.delete_unmatched(path, old, &matched, next_old..old.len());
+    }
+
+    fn delete_unmatched(
+        &mut self,
+        path: &[String],
+        old: &[Section],
+        matched: &[bool],
+        range: std::ops::Range<usize>,
+    ) {
+        for i in range.filter(|&i| !matched[i]) {
+            self.changes.push(ProvisionChange {
+                kind: ChangeKind::Deleted,
+                path: child_path(path, &old[i]),
+                old: Some(old[i].full_text()),
+                new: None,
+            });
+        }
+    }
+}
+
+fn find<F>(old: &[Section], matched: &[bool], section: &Section, key: F) -> Option<usize>
+where
+    F: Fn(&Section) -> Option<String>,
+{
+    let wanted = key(section).filter(|k| !k.is_empty())?;
+    old.iter()
+        .enumerate()
+        .find(|(i, s)| !matched[*i] && s.level == section.level && key(s).as_ref() == Some(&wanted))
+        .map(|(i, _)| i)
+}
+
+/// The text of a division without an enumerator or heading
+fn unlabeled_text(section: &Section) -> Option<String> {
+    section.label().is_empty().then(|| section.full_text())
+}
+
+fn child_path(path: &[String], section: &Section) -> Vec<String> {
+    let label = section.label();
+    let mut path = path.to_vec();
+    path.push(if label.is_empty() {
+        section.level.clone()
+    } else {
+        label
+    });
+    path
+}
+
+fn lines(text: Option<&str>) -> String {
+    match text {
+        Some(text) if !text.is_empty() => format!("{text}\n"),
+        _ => String::new(),
+    }
+}
+
+fn own_text(section: &Section) -> String {
+    let label = section.label();
+    match (label.is_empty(), section.text.is_empty()) {
+        (true, _) => section.text.clone(),
+        (false, true) => label,
+        (false, false) => format!("{label}\n{}", section.text),
+    }
+}
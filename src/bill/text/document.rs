@@ -0,0 +1,256 @@
+use crate::{error::XmlParseSnafu, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
+
+/// Elements that open a new structural division in both the bill DTD and USLM
+const LEVELS: &[&[u8]] = &[
+    b"division",
+    b"subdivision",
+    b"title",
+    b"subtitle",
+    b"chapter",
+    b"subchapter",
+    b"part",
+    b"subpart",
+    b"section",
+    b"subsection",
+    b"paragraph",
+    b"subparagraph",
+    b"clause",
+    b"subclause",
+    b"item",
+    b"subitem",
+    b"subsubitem",
+];
+
+/// Elements holding text that is quoted into existing law rather than being part of the bill
+const QUOTED: &[&[u8]] = &[b"quoted-block", b"quotedContent"];
+
+/// Elements whose contents are not part of the bill text
+const SKIPPED: &[&[u8]] = &[b"metadata", b"meta", b"toc"];
+
+/// Elements that start a new line in the plain text
+const BLOCKS: &[&[u8]] = &[b"text", b"p", b"chapeau", b"content", b"continuation-text"];
+
+/// A structural division of a bill: a section, subsection, paragraph and so on
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct Section {
+    /// The element the division was parsed from, e.g. `section` or `paragraph`
+    pub level: String,
+    /// The enumerator, e.g. `SEC. 2.` or `(a)`
+    pub enumerator: Option<String>,
+    pub heading: Option<String>,
+    /// Text belonging directly to this division, excluding nested divisions
+    pub text: String,
+    pub children: Vec<Section>,
+}
+
+impl Section {
+    fn new(level: &[u8]) -> Self {
+        Section {
+            level: String::from_utf8_lossy(level).into_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// The text of this division and all nested divisions
+    pub fn full_text(&self) -> String {
+        let mut lines = vec![];
+        self.collect_lines(&mut lines);
+        lines.join("\n")
+    }
+
+    /// The enumerator and heading joined, e.g. `SEC. 2. Definitions`
+    pub fn label(&self) -> String {
+        [self.enumerator.as_deref(), self.heading.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn collect_lines(&self, lines: &mut Vec<String>) {
+        let label = self.label();
+        if !label.is_empty() {
+            lines.push(label);
+        }
+        if !self.text.is_empty() {
+            lines.push(self.text.clone());
+        }
+        for child in &self.children {
+            child.collect_lines(lines);
+        }
+    }
+
+    fn finish(&mut self) {
+        self.text = normalize(&self.text);
+        self.enumerator = self.enumerator.take().map(|e| normalize(&e));
+        self.heading = self.heading.take().map(|h| normalize(&h));
+    }
+}
+
+/// A bill parsed from its bill DTD or USLM XML
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct BillDocument {
+    /// The official title of the bill
+    pub title: Option<String>,
+    /// The top level divisions of the bill body
+    pub sections: Vec<Section>,
+    /// The plain text of the whole bill
+    pub text: String,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Body,
+    Enumerator,
+    Heading,
+    Title,
+}
+
+impl BillDocument {
+    /// Parses the XML returned for [`FormatKind::FormattedXml`](super::FormatKind::FormattedXml)
+    pub fn parse(xml: &[u8]) -> Result<BillDocument> {
+        let mut reader = Reader::from_reader(xml);
+        let mut buf = Vec::new();
+
+        let mut document = BillDocument::default();
+        let mut title = String::new();
+        let mut text = String::new();
+        let mut stack: Vec<Section> = vec![];
+        let mut targets: Vec<Target> = vec![];
+        let mut quoted = 0usize;
+        let mut skipped = 0usize;
+
+        loop {
+            match reader.read_event_into(&mut buf).context(XmlParseSnafu)? {
+                Event::Start(e) => {
+                    let name = e.name();
+                    let name = name.as_ref();
+                    if skipped > 0 || SKIPPED.contains(&name) {
+                        skipped += 1;
+                    } else if QUOTED.contains(&name) {
+                        quoted += 1;
+                        text.push('\n');
+                        if let Some(section) = stack.last_mut() {
+                            section.text.push(' ');
+                        }
+                        targets.push(Target::Body);
+                    } else if quoted == 0 && LEVELS.contains(&name) {
+                        stack.push(Section::new(name));
+                        text.push('\n');
+                        targets.push(Target::Body);
+                    } else if let Some(target) = target_for(&e, quoted) {
+                        if target != Target::Title {
+                            text.push('\n');
+                        }
+                        targets.push(target);
+                    } else {
+                        if BLOCKS.contains(&name) || (quoted > 0 && is_label_or_level(name)) {
+                            text.push('\n');
+                            if let Some(section) = stack.last_mut() {
+                                section.text.push(' ');
+                            }
+                        }
+                        targets.push(targets.last().copied().unwrap_or(Target::Body));
+                    }
+                }
+                Event::End(e) => {
+                    let name = e.name();
+                    let name = name.as_ref();
+                    if skipped > 0 {
+                        skipped -= 1;
+                    } else if QUOTED.contains(&name) {
+                        targets.pop();
+                        quoted = quoted.saturating_sub(1);
+                        text.push('\n');
+                    } else if quoted == 0 && LEVELS.contains(&name) {
+                        targets.pop();
+                        if let Some(mut section) = stack.pop() {
+                            section.finish();
+                            match stack.last_mut() {
+                                Some(parent) => parent.children.push(section),
+                                None => document.sections.push(section),
+                            }
+                        }
+                        text.push('\n');
+                    } else {
+                        targets.pop();
+                        if BLOCKS.contains(&name) {
+                            text.push('\n');
+                        }
+                    }
+                }
+                Event::Empty(e) if skipped == 0 && e.name().as_ref() == b"linebreak" => {
+                    text.push('\n');
+                }
+                Event::Text(e) if skipped == 0 => {
+                    let chunk = e.unescape().context(XmlParseSnafu)?;
+                    text.push_str(&chunk);
+                    let target = targets.last().copied().unwrap_or(Target::Body);
+                    if target == Target::Title {
+                        title.push_str(&chunk);
+                    } else if let Some(section) = stack.last_mut() {
+                        match target {
+                            Target::Enumerator => section
+                                .enumerator
+                                .get_or_insert_with(String::new)
+                                .push_str(&chunk),
+                            Target::Heading => section
+                                .heading
+                                .get_or_insert_with(String::new)
+                                .push_str(&chunk),
+                            _ => section.text.push_str(&chunk),
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        let title = normalize(&title);
+        if !title.is_empty() {
+            document.title = Some(title);
+        }
+        document.text = text
+            .lines()
+            .map(normalize)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(document)
+    }
+
+    /// Iterates over every division of the bill, depth first
+    pub fn iter(&self) -> impl Iterator<Item = &Section> {
+        let mut pending: Vec<&Section> = self.sections.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let section = pending.pop()?;
+            pending.extend(section.children.iter().rev());
+            Some(section)
+        })
+    }
+}
+
+/// Decides whether an element captures the enumerator, heading or title of its parent
+fn target_for(e: &BytesStart, quoted: usize) -> Option<Target> {
+    match e.name().as_ref() {
+        b"official-title" | b"longTitle" => Some(Target::Title),
+        b"enum" | b"num" if quoted == 0 => Some(Target::Enumerator),
+        b"header" | b"heading" if quoted == 0 => Some(Target::Heading),
+        _ => None,
+    }
+}
+
+fn is_label_or_level(name: &[u8]) -> bool {
+    LEVELS.contains(&name) || matches!(name, b"enum" | b"num" | b"header" | b"heading")
+}
+
+fn normalize(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
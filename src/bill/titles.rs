@@ -3,28 +3,35 @@ use crate::{
     chamber::{Chamber, ChamberCode},
     pagination::Pagination,
     parameters::{HasParameters, PageParameters, Parameters},
+    version_code::TextVersionCode,
     Result,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Title {
-    pub bill_text_version_code: String,
+    pub bill_text_version_code: TextVersionCode,
     pub bill_text_version_name: String,
     pub chamber_code: ChamberCode,
     pub chamber_name: Chamber,
     pub title: String,
     pub title_type: String,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TitlesResponse {
     pub titles: Vec<Title>,
     pagination: Pagination,
 }
 
+crate::pagination::macros::paged_iterator!(TitlesResponse, Title, titles);
+
 #[derive(Debug)]
 pub struct TitlesHandler<'client> {
     handler: &'client BillHandler<'client>,
@@ -39,15 +46,49 @@ impl<'client> TitlesHandler<'client> {
         }
     }
 
-    pub async fn send(&self) -> Result<TitlesResponse> {
-        let path = format!(
+    fn path(&self) -> String {
+        format!(
             "/v3/bill/{congress}/{bill_type}/{bill_number}/titles",
             congress = self.handler.congress,
             bill_type = self.handler.bill_type,
             bill_number = self.handler.bill_number
-        );
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "titles",
+            skip_all,
+            fields(
+                congress = self.handler.congress,
+                bill_type = %self.handler.bill_type,
+                bill_number = self.handler.bill_number,
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<TitlesResponse> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.handler
+            .client
+            .get(&self.path(), Some(&self.parameters))
+            .await
+    }
 
-        self.handler.client.get(&path, Some(&self.parameters)).await
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.handler
+            .client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
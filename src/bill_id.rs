@@ -0,0 +1,52 @@
+use crate::bill_type::BillType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Identifies a bill across congresses, e.g. H.R. 3746 of the 118th Congress
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct BillId {
+    pub congress: u32,
+    pub bill_type: BillType,
+    pub number: u32,
+}
+
+impl BillId {
+    pub fn new(congress: u32, bill_type: BillType, number: u32) -> Self {
+        BillId {
+            congress,
+            bill_type,
+            number,
+        }
+    }
+
+    /// The bill's citation, e.g. `H.R. 3746`
+    pub fn citation(&self) -> String {
+        format!("{} {}", self.bill_type.citation(), self.number)
+    }
+
+    /// The bill's page on congress.gov
+    pub fn web_url(&self) -> Url {
+        let suffix = match (self.congress % 100, self.congress % 10) {
+            (11..=13, _) => "th",
+            (_, 1) => "st",
+            (_, 2) => "nd",
+            (_, 3) => "rd",
+            _ => "th",
+        };
+        let url = format!(
+            "https://www.congress.gov/bill/{}{suffix}-congress/{}/{}",
+            self.congress,
+            self.bill_type.slug(),
+            self.number
+        );
+        Url::parse(&url).expect("bill URLs are valid")
+    }
+}
+
+/// Formats the ID the way congress.gov bulk data does, e.g. `hr3746-118`
+impl std::fmt::Display for BillId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}-{}", self.bill_type, self.number, self.congress)
+    }
+}
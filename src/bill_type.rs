@@ -1,34 +1,64 @@
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum BillType {
     // House bill
-    #[serde(rename(deserialize = "HR"), alias = "hr")]
+    #[serde(rename = "HR", alias = "hr")]
     House,
     /// Senate bill
-    #[serde(rename(deserialize = "S"), alias = "s")]
+    #[serde(rename = "S", alias = "s")]
     Senate,
     /// House Joint Resoultion
-    #[serde(rename(deserialize = "HJRES"), alias = "hjres")]
+    #[serde(rename = "HJRES", alias = "hjres")]
     HouseJointResolution,
     /// Senate Joint Resoultion
-    #[serde(rename(deserialize = "SJRES"), alias = "sjres")]
+    #[serde(rename = "SJRES", alias = "sjres")]
     SenateJointResolution,
     /// House Concurrent Resolution
-    #[serde(rename(deserialize = "HCONRES"), alias = "hconres")]
+    #[serde(rename = "HCONRES", alias = "hconres")]
     HouseConcurrentResolution,
     /// Senate Concurrent Resolution
-    #[serde(rename(deserialize = "SCONRES"), alias = "sconres")]
+    #[serde(rename = "SCONRES", alias = "sconres")]
     SenateConcurrentResolution,
     /// House Resolution
-    #[serde(rename(deserialize = "HRES"), alias = "hres")]
+    #[serde(rename = "HRES", alias = "hres")]
     HouseResolution,
     /// Senate Resolution
-    #[serde(rename(deserialize = "SRES"), alias = "sres")]
+    #[serde(rename = "SRES", alias = "sres")]
     SenateResolution,
 }
 
+impl BillType {
+    /// The abbreviation used when citing a bill, e.g. `H.J.Res.`
+    pub fn citation(&self) -> &'static str {
+        match self {
+            Self::House => "H.R.",
+            Self::Senate => "S.",
+            Self::HouseJointResolution => "H.J.Res.",
+            Self::SenateJointResolution => "S.J.Res.",
+            Self::HouseConcurrentResolution => "H.Con.Res.",
+            Self::SenateConcurrentResolution => "S.Con.Res.",
+            Self::HouseResolution => "H.Res.",
+            Self::SenateResolution => "S.Res.",
+        }
+    }
+
+    /// The name congress.gov uses in its URLs, e.g. `house-joint-resolution`
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::House => "house-bill",
+            Self::Senate => "senate-bill",
+            Self::HouseJointResolution => "house-joint-resolution",
+            Self::SenateJointResolution => "senate-joint-resolution",
+            Self::HouseConcurrentResolution => "house-concurrent-resolution",
+            Self::SenateConcurrentResolution => "senate-concurrent-resolution",
+            Self::HouseResolution => "house-resolution",
+            Self::SenateResolution => "senate-resolution",
+        }
+    }
+}
+
 impl std::fmt::Display for BillType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -57,7 +87,7 @@ impl FromStr for BillType {
             "SCONRES" => Ok(Self::SenateConcurrentResolution),
             "HRES" => Ok(Self::HouseResolution),
             "SRES" => Ok(Self::SenateResolution),
-            &_ => unreachable!(),
+            _ => Err(()),
         }
     }
 }
@@ -1,17 +1,20 @@
 use crate::{
+    bill_id::BillId,
     bill_type::BillType,
     chamber::{Chamber, ChamberCode},
+    error::InvalidBillNumberSnafu,
     latest_action::LatestAction,
     pagination::Pagination,
     parameters::{HasParameters, PageParameters, Parameters, SortParameters},
     Client, Result,
 };
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
 use url::Url;
 
 // Types
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Bill {
     pub congress: u32,
@@ -25,10 +28,24 @@ pub struct Bill {
     pub update_date: NaiveDate,
     pub update_date_including_text: DateTime<Utc>,
     pub url: Url,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Bill {
+    /// Fails if the API returned a number that isn't numeric
+    pub fn id(&self) -> Result<BillId> {
+        let number = self.number.parse().context(InvalidBillNumberSnafu {
+            number: &self.number,
+        })?;
+        Ok(BillId::new(self.congress, self.bill_type, number))
+    }
 }
 
 // Response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct BillsResponse {
     pub bills: Vec<Bill>,
     pagination: Pagination,
@@ -65,7 +82,7 @@ impl<'client> BillsHandler<'client> {
         self
     }
 
-    pub async fn send(&self) -> Result<BillsResponse> {
+    fn path(&self) -> String {
         let mut path = String::from("/v3/bill");
         if let Some(congress) = self.congress {
             path.push_str(format!("/{congress}").as_str());
@@ -73,7 +90,35 @@ impl<'client> BillsHandler<'client> {
         if let Some(bill_type) = &self.bill_type {
             path.push_str(format!("/{bill_type}").as_str());
         }
-        self.client.get(&path, Some(&self.parameters)).await
+        path
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "bills",
+            skip_all,
+            fields(
+                congress = self.congress,
+                bill_type = self.bill_type.map(|t| t.to_string()),
+            )
+        )
+    )]
+    pub async fn send(&self) -> Result<BillsResponse> {
+        self.client.get(&self.path(), Some(&self.parameters)).await
+    }
+
+    /// The response as untyped JSON, including fields this crate doesn't model
+    pub async fn send_raw(&self) -> Result<serde_json::Value> {
+        self.client.get(&self.path(), Some(&self.parameters)).await
+    }
+
+    /// The response in the API's XML format, unparsed
+    #[cfg(feature = "xml")]
+    pub async fn send_xml(&self) -> Result<Vec<u8>> {
+        self.client
+            .get_xml(&self.path(), Some(&self.parameters))
+            .await
     }
 }
 
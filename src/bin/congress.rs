@@ -0,0 +1,375 @@
+//! Command line access to the bill endpoints
+//!
+//! ```text
+//! congress bills --congress 118 --type hr --limit 20
+//! congress --format csv bill 118 hr 3746 actions --all
+//! ```
+//!
+//! The key is read from `CONGRESS_API_KEY`, or from the `api_key` entry of
+//! `$XDG_CONFIG_HOME/congress/config.toml` (`~/.config/congress/config.toml`).
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use congress::{
+    bill_type::BillType,
+    error::{Error, WriteSnafu},
+    export::{export_pages, CsvWriter, RecordWriter, Tabular},
+    pagination::PagedResponse,
+    prelude::*,
+    Client, ClientBuilder,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use snafu::ResultExt;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+type CliResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// The longest a cell can be in table output before it is cut short
+const MAX_CELL_WIDTH: usize = 48;
+
+#[derive(Debug, Parser)]
+#[command(name = "congress", version, about = "Query the Congress.gov API")]
+struct Cli {
+    /// How to print the results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// The config file to read the API key from
+    #[arg(long, global = true, env = "CONGRESS_CONFIG")]
+    config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List bills, most recently updated first
+    Bills {
+        #[arg(long)]
+        congress: Option<u32>,
+        /// The bill type, e.g. `hr` or `sjres`. Requires `--congress`.
+        #[arg(long = "type", value_parser = parse_bill_type, requires = "congress")]
+        bill_type: Option<BillType>,
+        #[command(flatten)]
+        page: Page,
+    },
+    /// Show a bill, or one of its lists
+    #[command(group(
+        ArgGroup::new("paging")
+            .args(["limit", "offset", "all"])
+            .multiple(true)
+            .requires("resource")
+    ))]
+    Bill {
+        congress: u32,
+        #[arg(value_parser = parse_bill_type)]
+        bill_type: BillType,
+        number: u32,
+        #[arg(value_enum)]
+        resource: Option<Resource>,
+        #[command(flatten)]
+        page: Page,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Resource {
+    Actions,
+    Amendments,
+    Committees,
+    Cosponsors,
+    RelatedBills,
+    Subjects,
+    Summaries,
+    Text,
+    Titles,
+}
+
+#[derive(Debug, Args)]
+struct Page {
+    /// The number of results per page, up to 250
+    #[arg(long)]
+    limit: Option<u32>,
+    #[arg(long)]
+    offset: Option<u32>,
+    /// Follow the pagination links and print every page
+    #[arg(long)]
+    all: bool,
+}
+
+/// The contents of the config file
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    api_key: Option<String>,
+    base_url: Option<String>,
+}
+
+fn parse_bill_type(s: &str) -> Result<BillType, String> {
+    s.parse().map_err(|_| {
+        format!(
+            "unknown bill type `{s}`, expected hr, s, hjres, sjres, hconres, sconres, hres or sres"
+        )
+    })
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("congress").join("config.toml"))
+}
+
+/// Reads the config file. A missing file is only an error if it was asked for.
+fn load_config(path: Option<&Path>) -> CliResult<Config> {
+    let (path, required) = match path {
+        Some(path) => (path.to_path_buf(), true),
+        None => match default_config_path() {
+            Some(path) => (path, false),
+            None => return Ok(Config::default()),
+        },
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => Ok(toml::from_str(&contents)
+            .map_err(|e| format!("invalid config file {}: {e}", path.display()))?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && !required => Ok(Config::default()),
+        Err(e) => Err(format!("couldn't read config file {}: {e}", path.display()).into()),
+    }
+}
+
+fn client(config: Config) -> CliResult<Client> {
+    let mut builder = match (ClientBuilder::from_env(), config.api_key) {
+        (Ok(builder), _) => builder,
+        (Err(Error::MissingApiKey { .. }), Some(key)) => ClientBuilder::new(key),
+        (Err(Error::MissingApiKey { var }), None) => {
+            return Err(format!("no API key: set {var} or add `api_key` to the config file").into())
+        }
+        (Err(e), _) => return Err(e.into()),
+    };
+    if let Some(base_url) = config.base_url {
+        builder = builder.base_url(base_url)?;
+    }
+    Ok(builder.build()?)
+}
+
+/// The items of `first`, and of every page after it if `all` is set
+async fn collect<P, T>(client: &Client, first: P, all: bool) -> congress::Result<Vec<T>>
+where
+    P: DeserializeOwned + PagedResponse<T>,
+    T: Clone,
+{
+    let mut items = first.get_items().clone();
+    let mut page = if all {
+        client.next(&first).await?
+    } else {
+        None
+    };
+    while let Some(current) = page {
+        items.extend(current.get_items().iter().cloned());
+        page = client.next(&current).await?;
+    }
+    Ok(items)
+}
+
+/// Prints the items of `first`, and of every page after it if `all` is set.
+/// JSON and CSV are written a page at a time; a table needs every row to size
+/// its columns, so it is printed once all pages are in.
+async fn print_pages<P, T>(client: &Client, first: P, all: bool, format: Format) -> CliResult<()>
+where
+    P: DeserializeOwned + PagedResponse<T>,
+    T: Tabular + Clone,
+{
+    let mut out = std::io::stdout().lock();
+    match format {
+        Format::Json => {
+            let mut writer = JsonWriter::new(&mut out);
+            write_pages(client, first, all, &mut writer).await?;
+            writer.finish()?;
+        }
+        Format::Csv => {
+            let mut writer = CsvWriter::new(&mut out);
+            write_pages(client, first, all, &mut writer).await?;
+            writer.into_inner()?;
+        }
+        Format::Table => print_table(&mut out, &collect(client, first, all).await?)?,
+    }
+    Ok(())
+}
+
+async fn write_pages<P, T, S>(
+    client: &Client,
+    first: P,
+    all: bool,
+    writer: &mut S,
+) -> congress::Result<usize>
+where
+    P: DeserializeOwned + PagedResponse<T>,
+    S: RecordWriter<T>,
+{
+    if all {
+        export_pages(client, first, writer).await
+    } else {
+        writer.write_all(first.get_items())
+    }
+}
+
+fn print<T: Tabular>(items: &[T], format: Format) -> CliResult<()> {
+    let mut out = std::io::stdout().lock();
+    match format {
+        Format::Json => {
+            let mut writer = JsonWriter::new(&mut out);
+            writer.write_all(items)?;
+            writer.finish()?;
+        }
+        Format::Csv => {
+            let mut writer = CsvWriter::new(&mut out);
+            writer.write_all(items)?;
+            writer.into_inner()?;
+        }
+        Format::Table => print_table(&mut out, items)?,
+    }
+    Ok(())
+}
+
+/// Writes items one at a time as the elements of a JSON array
+struct JsonWriter<W: Write> {
+    writer: W,
+    started: bool,
+}
+
+impl<W: Write> JsonWriter<W> {
+    fn new(writer: W) -> Self {
+        JsonWriter {
+            writer,
+            started: false,
+        }
+    }
+
+    /// Closes the array, which is empty if nothing was written
+    fn finish(mut self) -> std::io::Result<()> {
+        let close = if self.started { "\n]\n" } else { "[]\n" };
+        self.writer.write_all(close.as_bytes())?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write, T: Serialize> RecordWriter<T> for JsonWriter<W> {
+    fn write(&mut self, item: &T) -> congress::Result<()> {
+        let separator = if self.started { ",\n" } else { "[\n" };
+        self.started = true;
+        self.writer
+            .write_all(separator.as_bytes())
+            .context(WriteSnafu)?;
+        serde_json::to_writer_pretty(&mut self.writer, item)
+            .map_err(std::io::Error::from)
+            .context(WriteSnafu)
+    }
+}
+
+fn print_table<T: Tabular>(out: &mut impl Write, items: &[T]) -> std::io::Result<()> {
+    let rows: Vec<Vec<String>> = items
+        .iter()
+        .map(|item| item.row().iter().map(|cell| shorten(cell)).collect())
+        .collect();
+    let mut widths: Vec<usize> = T::COLUMNS.iter().map(|c| c.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header = T::COLUMNS.iter().map(|c| c.to_string()).collect();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        writeln!(out, "{}", line.join("  ").trim_end())?;
+    }
+    Ok(())
+}
+
+/// Puts a cell on one line and cuts it to [`MAX_CELL_WIDTH`]
+fn shorten(cell: &str) -> String {
+    let cell = cell.split_whitespace().collect::<Vec<_>>().join(" ");
+    if cell.chars().count() <= MAX_CELL_WIDTH {
+        return cell;
+    }
+    let mut short: String = cell.chars().take(MAX_CELL_WIDTH - 1).collect();
+    short.push('…');
+    short
+}
+
+macro_rules! list {
+    ($client:expr, $handler:expr, $page:expr, $format:expr) => {{
+        let mut handler = $handler;
+        if let Some(limit) = $page.limit {
+            handler = handler.limit(limit);
+        }
+        if let Some(offset) = $page.offset {
+            handler = handler.offset(offset);
+        }
+        let first = handler.send().await?;
+        print_pages($client, first, $page.all, $format).await
+    }};
+}
+
+async fn run(cli: Cli) -> CliResult<()> {
+    let client = client(load_config(cli.config.as_deref())?)?;
+    let format = cli.format;
+
+    match cli.command {
+        Command::Bills {
+            congress,
+            bill_type,
+            page,
+        } => {
+            let mut handler = client.bills();
+            if let Some(congress) = congress {
+                handler = handler.congress(congress);
+            }
+            if let Some(bill_type) = bill_type {
+                handler = handler.bill_type(bill_type);
+            }
+            list!(&client, handler, page, format)
+        }
+        Command::Bill {
+            congress,
+            bill_type,
+            number,
+            resource,
+            page,
+        } => {
+            let bill = client.bill(congress, bill_type, number);
+            match resource {
+                None => print(&[bill.send().await?.bill], format),
+                Some(Resource::Actions) => list!(&client, bill.actions(), page, format),
+                Some(Resource::Amendments) => list!(&client, bill.amendments(), page, format),
+                Some(Resource::Committees) => list!(&client, bill.committees(), page, format),
+                Some(Resource::Cosponsors) => list!(&client, bill.cosponsors(), page, format),
+                Some(Resource::RelatedBills) => list!(&client, bill.related_bills(), page, format),
+                Some(Resource::Subjects) => list!(&client, bill.subjects(), page, format),
+                Some(Resource::Summaries) => list!(&client, bill.summaries(), page, format),
+                Some(Resource::Text) => list!(&client, bill.text(), page, format),
+                Some(Resource::Titles) => list!(&client, bill.titles(), page, format),
+            }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = run(Cli::parse()).await {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,254 @@
+//! Convert bills and their parts to Arrow record batches and Parquet files
+//!
+//! Every [`Columnar`] model has a fixed schema: dates are `Date32`, timestamps
+//! are UTC milliseconds and chambers are dictionary-encoded strings, so the
+//! files load into DuckDB or any other Arrow based engine as is.
+//!
+//! Actions, cosponsors and sponsors are written as `(BillId, &T)` rows, built
+//! with [`rows_for`]. Their batches lead with the bill's `congress`, `type`
+//! and `number` columns, so they join to the bills table on those three.
+//!
+//! ```no_run
+//! # async fn run(client: congress::Client) -> congress::Result<()> {
+//! use congress::{columnar::ParquetWriter, prelude::*};
+//!
+//! let file = std::fs::File::create("bills.parquet").unwrap();
+//! let mut writer = ParquetWriter::new(file)?;
+//! let mut page = client.bills().congress(118).limit(250).send().await?;
+//! loop {
+//!     writer.write(&page.bills)?;
+//!     match client.next(&page).await? {
+//!         Some(next) => page = next,
+//!         None => break,
+//!     }
+//! }
+//! writer.close()?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    bill::{actions::Action, cosponsors::Cosponsor, Sponsor},
+    bill_id::BillId,
+    bills::Bill,
+    chamber::Chamber,
+    error::{ArrowSnafu, ParquetSnafu},
+    Result,
+};
+use arrow_array::{
+    types::{Date32Type, Int8Type},
+    ArrayRef, BooleanArray, Date32Array, DictionaryArray, RecordBatch, StringArray,
+    TimestampMillisecondArray, UInt32Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, NaiveDate, Utc};
+use parquet::arrow::ArrowWriter;
+use serde::Serialize;
+use snafu::ResultExt;
+use std::{io::Write, marker::PhantomData, sync::Arc};
+
+/// A model that can be converted to an Arrow [`RecordBatch`]
+pub trait Columnar: Sized {
+    fn schema() -> SchemaRef;
+
+    fn record_batch(items: &[Self]) -> Result<RecordBatch>;
+}
+
+/// Pairs each item with the bill it belongs to, for writing bill parts
+pub fn rows_for<T>(bill: BillId, items: &[T]) -> Vec<(BillId, &T)> {
+    items.iter().map(|item| (bill, item)).collect()
+}
+
+fn chamber_type() -> DataType {
+    DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8))
+}
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+}
+
+fn strings<'a, T, S: AsRef<str>>(items: &'a [T], f: impl Fn(&'a T) -> S) -> ArrayRef {
+    Arc::new(StringArray::from_iter_values(items.iter().map(f)))
+}
+
+/// The value's name as it appears in the API's JSON
+fn serde_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+fn optional_strings<'a, T, S: AsRef<str>>(
+    items: &'a [T],
+    f: impl Fn(&'a T) -> Option<S>,
+) -> ArrayRef {
+    Arc::new(items.iter().map(f).collect::<StringArray>())
+}
+
+fn numbers<T>(items: &[T], f: impl Fn(&T) -> Option<u32>) -> ArrayRef {
+    Arc::new(items.iter().map(f).collect::<UInt32Array>())
+}
+
+fn booleans<T>(items: &[T], f: impl Fn(&T) -> bool) -> ArrayRef {
+    Arc::new(items.iter().map(|i| Some(f(i))).collect::<BooleanArray>())
+}
+
+fn dates<T>(items: &[T], f: impl Fn(&T) -> NaiveDate) -> ArrayRef {
+    Arc::new(Date32Array::from_iter_values(
+        items.iter().map(|i| Date32Type::from_naive_date(f(i))),
+    ))
+}
+
+fn optional_dates<T>(items: &[T], f: impl Fn(&T) -> Option<NaiveDate>) -> ArrayRef {
+    let days = items.iter().map(|i| f(i).map(Date32Type::from_naive_date));
+    Arc::new(days.collect::<Date32Array>())
+}
+
+fn timestamps<T>(items: &[T], f: impl Fn(&T) -> DateTime<Utc>) -> ArrayRef {
+    let millis = items.iter().map(|i| f(i).timestamp_millis());
+    Arc::new(TimestampMillisecondArray::from_iter_values(millis).with_timezone("UTC"))
+}
+
+fn chambers<T>(items: &[T], f: impl Fn(&T) -> Option<Chamber>) -> ArrayRef {
+    let names = items.iter().map(|i| {
+        f(i).map(|chamber| match chamber {
+            Chamber::House => "House",
+            Chamber::Senate => "Senate",
+        })
+    });
+    Arc::new(names.collect::<DictionaryArray<Int8Type>>())
+}
+
+macro_rules! columnar {
+    (bill part $type:ty, |$items:ident| {
+        $($name:literal: $data_type:expr, $nullable:literal => $array:expr),+ $(,)?
+    }) => {
+        impl<'a> Columnar for (BillId, &'a $type) {
+            fn schema() -> SchemaRef {
+                Arc::new(Schema::new(vec![
+                    Field::new("congress", DataType::UInt32, false),
+                    Field::new("type", DataType::Utf8, false),
+                    Field::new("number", DataType::Utf8, false),
+                    $(Field::new($name, $data_type, $nullable)),+
+                ]))
+            }
+
+            fn record_batch($items: &[Self]) -> Result<RecordBatch> {
+                let columns = vec![
+                    numbers($items, |(bill, _)| Some(bill.congress)),
+                    strings($items, |(bill, _)| bill.bill_type.to_string().to_uppercase()),
+                    strings($items, |(bill, _)| bill.number.to_string()),
+                    $($array),+
+                ];
+                RecordBatch::try_new(Self::schema(), columns).context(ArrowSnafu)
+            }
+        }
+    };
+    ($type:ty, |$items:ident| {
+        $($name:literal: $data_type:expr, $nullable:literal => $array:expr),+ $(,)?
+    }) => {
+        impl Columnar for $type {
+            fn schema() -> SchemaRef {
+                Arc::new(Schema::new(vec![$(Field::new($name, $data_type, $nullable)),+]))
+            }
+
+            fn record_batch($items: &[Self]) -> Result<RecordBatch> {
+                RecordBatch::try_new(Self::schema(), vec![$($array),+]).context(ArrowSnafu)
+            }
+        }
+    };
+}
+
+columnar!(Bill, |bills| {
+    "congress": DataType::UInt32, false => numbers(bills, |b| Some(b.congress)),
+    "type": DataType::Utf8, false => strings(bills, |b| b.bill_type.to_string().to_uppercase()),
+    "number": DataType::Utf8, false => strings(bills, |b| &b.number),
+    "title": DataType::Utf8, false => strings(bills, |b| &b.title),
+    "origin_chamber": chamber_type(), false => chambers(bills, |b| Some(b.origin_chamber)),
+    "latest_action_date": DataType::Date32, false => dates(bills, |b| b.latest_action.action_date),
+    "latest_action_text": DataType::Utf8, false => strings(bills, |b| &b.latest_action.text),
+    "update_date": DataType::Date32, false => dates(bills, |b| b.update_date),
+    "update_date_including_text": timestamp_type(), false => timestamps(bills, |b| b.update_date_including_text),
+    "url": DataType::Utf8, false => strings(bills, |b| &b.url),
+});
+
+columnar!(bill part Action, |actions| {
+    "action_date": DataType::Date32, false => dates(actions, |(_, a)| a.action_date),
+    "action_code": DataType::Utf8, true => optional_strings(actions, |(_, a)| a.action_code.as_ref()),
+    "action_type": DataType::Utf8, false => strings(actions, |(_, a)| serde_name(&a.action_type)),
+    "text": DataType::Utf8, false => strings(actions, |(_, a)| &a.text),
+    "source_system_code": DataType::UInt32, true => numbers(actions, |(_, a)| a.source_system.code.map(u32::from)),
+    "chamber": chamber_type(), true => chambers(actions, |(_, a)| a.source_system.code.and_then(|c| c.chamber())),
+    "committees": DataType::Utf8, true => optional_strings(actions, |(_, a)| {
+        let codes: Vec<&str> = a.committees.iter().map(|c| c.system_code.as_str()).collect();
+        (!codes.is_empty()).then(|| codes.join(";"))
+    }),
+    "roll_numbers": DataType::Utf8, true => optional_strings(actions, |(_, a)| {
+        a.recorded_votes.as_ref().map(|votes| {
+            votes.iter().map(|v| v.roll_number.to_string()).collect::<Vec<_>>().join(";")
+        })
+    }),
+});
+
+columnar!(bill part Cosponsor, |cosponsors| {
+    "bioguide_id": DataType::Utf8, false => strings(cosponsors, |(_, c)| &c.bioguide_id),
+    "full_name": DataType::Utf8, false => strings(cosponsors, |(_, c)| &c.full_name),
+    "first_name": DataType::Utf8, false => strings(cosponsors, |(_, c)| &c.first_name),
+    "middle_name": DataType::Utf8, true => optional_strings(cosponsors, |(_, c)| c.middle_name.as_ref()),
+    "last_name": DataType::Utf8, false => strings(cosponsors, |(_, c)| &c.last_name),
+    "party": DataType::Utf8, false => strings(cosponsors, |(_, c)| c.party.code()),
+    "state": DataType::Utf8, false => strings(cosponsors, |(_, c)| c.state.code()),
+    "district": DataType::UInt32, true => numbers(cosponsors, |(_, c)| c.district.map(u32::from)),
+    "is_original_cosponsor": DataType::Boolean, false => booleans(cosponsors, |(_, c)| c.is_original_cosponsor),
+    "sponsorship_date": DataType::Date32, false => dates(cosponsors, |(_, c)| c.sponsorship_date),
+    "sponsorship_withdrawn_date": DataType::Date32, true => optional_dates(cosponsors, |(_, c)| c.sponsorship_withdrawn_date),
+    "url": DataType::Utf8, false => strings(cosponsors, |(_, c)| &c.url),
+});
+
+columnar!(bill part Sponsor, |members| {
+    "bioguide_id": DataType::Utf8, false => strings(members, |(_, m)| &m.bioguide_id),
+    "full_name": DataType::Utf8, false => strings(members, |(_, m)| &m.full_name),
+    "first_name": DataType::Utf8, false => strings(members, |(_, m)| &m.first_name),
+    "middle_name": DataType::Utf8, true => optional_strings(members, |(_, m)| m.middle_name.as_ref()),
+    "last_name": DataType::Utf8, false => strings(members, |(_, m)| &m.last_name),
+    "party": DataType::Utf8, false => strings(members, |(_, m)| m.party.code()),
+    "state": DataType::Utf8, false => strings(members, |(_, m)| m.state.code()),
+    "district": DataType::UInt32, true => numbers(members, |(_, m)| m.district.map(u32::from)),
+    "url": DataType::Utf8, false => strings(members, |(_, m)| &m.url),
+});
+
+/// Writes [`Columnar`] items to a Parquet file, one row group per call to [`ParquetWriter::write`]
+pub struct ParquetWriter<W: Write + Send, T> {
+    writer: ArrowWriter<W>,
+    _item: PhantomData<T>,
+}
+
+impl<W: Write + Send, T: Columnar> ParquetWriter<W, T> {
+    pub fn new(writer: W) -> Result<Self> {
+        Ok(ParquetWriter {
+            writer: ArrowWriter::try_new(writer, T::schema(), None).context(ParquetSnafu)?,
+            _item: PhantomData,
+        })
+    }
+
+    pub fn write(&mut self, items: &[T]) -> Result<()> {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let batch = T::record_batch(items)?;
+        self.writer.write(&batch).context(ParquetSnafu)?;
+        self.writer.flush().context(ParquetSnafu)
+    }
+
+    /// Writes the file footer and returns the underlying writer
+    pub fn close(self) -> Result<W> {
+        self.writer.into_inner().context(ParquetSnafu)
+    }
+}
+
+/// Writes `items` as a complete Parquet file
+pub fn write_parquet<W: Write + Send, T: Columnar>(items: &[T], writer: W) -> Result<W> {
+    let mut writer = ParquetWriter::new(writer)?;
+    writer.write(items)?;
+    writer.close()
+}
@@ -1,5 +1,8 @@
+use crate::{bill::text::FormatKind, chamber::Chamber, transport::HttpResponse};
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use snafu::Snafu;
+use std::time::Duration;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -23,6 +26,52 @@ impl std::fmt::Display for CongressError {
     }
 }
 
+/// The body of an error response
+#[derive(Debug, Clone)]
+pub enum ErrorBody {
+    /// The API's own JSON error
+    Api(ErrorDetails),
+    /// Anything else, e.g. an HTML error page from a proxy
+    Raw(String),
+}
+
+impl ErrorBody {
+    pub(crate) fn parse(body: &[u8]) -> Self {
+        match serde_json::from_slice::<CongressError>(body) {
+            Ok(error) => Self::Api(error.error),
+            Err(_) => Self::Raw(String::from_utf8_lossy(body).into_owned()),
+        }
+    }
+
+    /// The API's error code, e.g. `OVER_RATE_LIMIT`
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Self::Api(details) => Some(&details.code),
+            Self::Raw(_) => None,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Api(details) => &details.message,
+            Self::Raw(body) => body,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        const MAX_RAW: usize = 200;
+        match self {
+            Self::Api(details) => write!(f, "{}", details.message),
+            Self::Raw(body) if body.chars().count() > MAX_RAW => {
+                write!(f, "{}...", body.chars().take(MAX_RAW).collect::<String>())
+            }
+            Self::Raw(body) => write!(f, "{body}"),
+        }
+    }
+}
+
 #[derive(Snafu, Debug)]
 #[snafu(visibility(pub))]
 pub enum Error {
@@ -56,22 +105,214 @@ pub enum Error {
         source: reqwest::Error,
     },
 
-    #[snafu(display("JSON Error: {}", source))]
+    #[snafu(display("JSON Error in the response from {}: {}", path, source))]
     JsonParseError {
+        /// The request path
+        path: String,
         /// The source error
         source: serde_path_to_error::Error<serde_json::Error>,
     },
 
+    #[snafu(display("XML Error: {}", source))]
+    XmlParseError {
+        /// The source error
+        source: quick_xml::Error,
+    },
+
+    #[snafu(display("XML Error: {}", source))]
+    XmlDeserializeError {
+        /// The source error
+        source: quick_xml::DeError,
+    },
+
+    #[snafu(display("Bill number {:?} is not numeric: {}", number, source))]
+    InvalidBillNumber {
+        number: String,
+        /// The source error
+        source: std::num::ParseIntError,
+    },
+
+    /// Senate roll calls identify members by LIS ID until bioguide IDs are
+    /// filled in with [`RollCall::set_bioguide_ids`](crate::votes::RollCall::set_bioguide_ids)
+    #[snafu(display(
+        "{:?} roll call {} has members without a bioguide ID",
+        chamber,
+        roll_number
+    ))]
+    MissingBioguideId { chamber: Chamber, roll_number: u32 },
+
+    #[snafu(display("Text version is not available as {}", kind))]
+    FormatUnavailable { kind: FormatKind },
+
+    #[cfg(feature = "testing")]
+    #[snafu(display("Fixture Error at {}: {}", path.display(), source))]
+    FixtureError {
+        path: std::path::PathBuf,
+        /// The source error
+        source: std::io::Error,
+    },
+
+    #[cfg(feature = "testing")]
+    #[snafu(display("Mock Server Error: {}", source))]
+    MockServerError {
+        /// The source error
+        source: hyper::Error,
+    },
+
+    #[snafu(display("No API key set in {}", var))]
+    MissingApiKey { var: &'static str },
+
+    #[cfg(feature = "export")]
+    #[snafu(display("CSV Error: {}", source))]
+    CsvError {
+        /// The source error
+        source: csv::Error,
+    },
+
+    #[cfg(feature = "parquet")]
+    #[snafu(display("Arrow Error: {}", source))]
+    ArrowError {
+        /// The source error
+        source: arrow_schema::ArrowError,
+    },
+
+    #[cfg(feature = "parquet")]
+    #[snafu(display("Parquet Error: {}", source))]
+    ParquetError {
+        /// The source error
+        source: parquet::errors::ParquetError,
+    },
+
+    #[cfg(feature = "store")]
+    #[snafu(display("Store Error: {}", source))]
+    StoreError {
+        /// The source error
+        source: rusqlite::Error,
+    },
+
+    #[snafu(display("Write Error: {}", source))]
+    WriteError {
+        /// The source error
+        source: std::io::Error,
+    },
+
     #[snafu(display("Parameter Error: {}", source))]
     ParameterError { source: serde_urlencoded::de::Error },
 
-    #[snafu(display("Congress API Error: {}", source))]
-    Congress {
+    #[snafu(display("Parameter Error: {}", source))]
+    ParameterEncodeError {
+        source: serde_urlencoded::ser::Error,
+    },
+
+    #[snafu(display("Unexpected HTTP status {} from {}", status, url))]
+    UnexpectedStatus { status: u16, url: url::Url },
+
+    #[snafu(display("Transport Error: {}", source))]
+    TransportError {
         /// The source error
-        source: CongressError,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// The API answered 404
+    #[snafu(display("Not found: {}: {}", path, body))]
+    NotFound { path: String, body: ErrorBody },
+
+    /// The API answered 429 because the key is over its hourly quota
+    #[snafu(display("Rate limited on {}: {}", path, body))]
+    RateLimited {
+        path: String,
+        /// How long to wait before retrying, from the `Retry-After` header
+        retry_after: Option<Duration>,
+        body: ErrorBody,
+    },
+
+    /// The API answered 401 or 403, usually because the key is missing or invalid
+    #[snafu(display("Unauthorized ({}) on {}: {}", status, path, body))]
+    Unauthorized {
+        status: u16,
+        path: String,
+        body: ErrorBody,
+    },
+
+    /// The API answered with a 5xx status
+    #[snafu(display("Server Error ({}) on {}: {}", status, path, body))]
+    ServerError {
+        status: u16,
+        path: String,
+        body: ErrorBody,
+    },
+
+    /// Any other unsuccessful response
+    #[snafu(display("Congress API Error ({}) on {}: {}", status, path, body))]
+    Congress {
+        status: u16,
+        path: String,
+        body: ErrorBody,
     },
 
     Unknown {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 }
+
+impl Error {
+    /// Classifies an unsuccessful response to a request for `path`
+    pub(crate) fn from_response(path: &str, response: &HttpResponse) -> Error {
+        let path = path.to_string();
+        let body = ErrorBody::parse(&response.body);
+        match response.status {
+            404 => Error::NotFound { path, body },
+            429 => Error::RateLimited {
+                path,
+                retry_after: response.header("Retry-After").and_then(parse_retry_after),
+                body,
+            },
+            status @ (401 | 403) => Error::Unauthorized { status, path, body },
+            status @ 500..=599 => Error::ServerError { status, path, body },
+            status => Error::Congress { status, path, body },
+        }
+    }
+
+    /// The HTTP status of the response that caused the error, if any
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::NotFound { .. } => Some(404),
+            Error::RateLimited { .. } => Some(429),
+            Error::Unauthorized { status, .. }
+            | Error::ServerError { status, .. }
+            | Error::Congress { status, .. }
+            | Error::UnexpectedStatus { status, .. } => Some(*status),
+            Error::SendError { source } | Error::ResponseError { source } => {
+                source.status().map(|s| s.as_u16())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the same request might succeed if sent again later
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RateLimited { .. }
+            | Error::ServerError { .. }
+            | Error::TransportError { .. } => true,
+            Error::SendError { source } | Error::ResponseError { source } => {
+                source.is_timeout() || source.is_connect()
+            }
+            Error::UnexpectedStatus { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// `Retry-After` is either a number of seconds or an HTTP date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&Utc) - Utc::now())
+        .to_std()
+        .ok()
+        .or(Some(Duration::ZERO))
+}
@@ -0,0 +1,284 @@
+//! Write bills and their parts to CSV and JSON Lines
+//!
+//! Every [`Tabular`] model has a fixed set of CSV columns, with nested fields
+//! flattened as `latest_action.text` and lists joined with `;`. Both writers
+//! take one item at a time, and [`export_pages`] follows the pagination links
+//! so only one page is held in memory.
+//!
+//! ```no_run
+//! # async fn run(client: congress::Client) -> congress::Result<()> {
+//! use congress::{export::{export_pages, CsvWriter}, prelude::*};
+//!
+//! let first = client.bills().congress(118).limit(250).send().await?;
+//! let mut csv = CsvWriter::new(std::fs::File::create("bills.csv").unwrap());
+//! export_pages(&client, first, &mut csv).await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    bill::{
+        self, actions::Action, amendments::Amendment, committees::Committee, cosponsors::Cosponsor,
+        related_bills::RelatedBill, subjects::LegislativeSubject, summaries::Summary,
+        text::TextVersion, titles::Title,
+    },
+    bills::Bill,
+    error::{CsvSnafu, WriteSnafu},
+    pagination::PagedResponse,
+    Client, Result,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::ResultExt;
+use std::{io::Write, marker::PhantomData};
+
+/// A model that can be written as a single CSV row
+pub trait Tabular: Serialize {
+    /// The column names, in the order [`Tabular::row`] returns the cells
+    const COLUMNS: &'static [&'static str];
+
+    fn row(&self) -> Vec<String>;
+}
+
+/// Formats a value the way it appears in the API's JSON, without quotes
+fn cell<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::Null) | Err(_) => String::new(),
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(value) => value.to_string(),
+    }
+}
+
+fn joined<'a, T: Serialize + 'a>(values: impl IntoIterator<Item = &'a T>) -> String {
+    values.into_iter().map(cell).collect::<Vec<_>>().join(";")
+}
+
+macro_rules! tabular {
+    ($type:ty, |$item:ident| { $($column:literal => $value:expr),+ $(,)? }) => {
+        impl Tabular for $type {
+            const COLUMNS: &'static [&'static str] = &[$($column),+];
+
+            fn row(&self) -> Vec<String> {
+                let $item = self;
+                vec![$($value),+]
+            }
+        }
+    };
+}
+
+tabular!(Bill, |bill| {
+    "congress" => cell(&bill.congress),
+    "type" => cell(&bill.bill_type),
+    "number" => cell(&bill.number),
+    "title" => cell(&bill.title),
+    "origin_chamber" => cell(&bill.origin_chamber),
+    "origin_chamber_code" => cell(&bill.origin_chamber_code),
+    "latest_action.action_date" => cell(&bill.latest_action.action_date),
+    "latest_action.action_time" => cell(&bill.latest_action.action_time),
+    "latest_action.text" => cell(&bill.latest_action.text),
+    "update_date" => cell(&bill.update_date),
+    "update_date_including_text" => cell(&bill.update_date_including_text),
+    "url" => cell(&bill.url),
+});
+
+tabular!(bill::Bill, |bill| {
+    "congress" => cell(&bill.congress),
+    "type" => cell(&bill.bill_type),
+    "number" => cell(&bill.number),
+    "title" => cell(&bill.title),
+    "origin_chamber" => cell(&bill.origin_chamber),
+    "introduced_date" => cell(&bill.introduced_date),
+    "policy_area.name" => cell(&bill.policy_area.as_ref().map(|p| &p.name)),
+    "sponsors.bioguide_id" => joined(bill.sponsors.iter().flatten().map(|s| &s.bioguide_id)),
+    "cosponsors.count" => cell(&bill.cosponsors.as_ref().map(|c| c.count)),
+    "latest_action.action_date" => cell(&bill.latest_action.action_date),
+    "latest_action.text" => cell(&bill.latest_action.text),
+    "laws.number" => joined(bill.laws.iter().flatten().map(|l| &l.number)),
+    "update_date" => cell(&bill.update_date),
+    "update_date_including_text" => cell(&bill.update_date_including_text),
+});
+
+tabular!(Action, |action| {
+    "action_date" => cell(&action.action_date),
+    "action_code" => cell(&action.action_code),
+    "type" => cell(&action.action_type),
+    "text" => cell(&action.text),
+    "source_system.code" => cell(&action.source_system.code),
+    "source_system.name" => cell(&action.source_system.name),
+    "calendar_number.calendar" => cell(&action.calendar_number.as_ref().map(|c| &c.calendar)),
+    "committees.system_code" => joined(action.committees.iter().map(|c| &c.system_code)),
+    "recorded_votes.roll_number" => joined(action.recorded_votes.iter().flatten().map(|v| &v.roll_number)),
+});
+
+tabular!(Amendment, |amendment| {
+    "congress" => cell(&amendment.congress),
+    "type" => cell(&amendment.amendment_type),
+    "number" => cell(&amendment.number),
+    "description" => cell(&amendment.description),
+    "latest_action.action_date" => cell(&amendment.latest_action.action_date),
+    "latest_action.text" => cell(&amendment.latest_action.text),
+    "update_date" => cell(&amendment.update_date),
+    "url" => cell(&amendment.url),
+});
+
+tabular!(Committee, |committee| {
+    "system_code" => cell(&committee.committee.system_code),
+    "name" => cell(&committee.committee.name),
+    "chamber" => cell(&committee.chamber),
+    "type" => cell(&committee.committee_type),
+    "activities.name" => joined(committee.activities.iter().map(|a| &a.name)),
+    "activities.date" => joined(committee.activities.iter().map(|a| &a.date)),
+    "url" => cell(&committee.committee.url),
+});
+
+tabular!(Cosponsor, |cosponsor| {
+    "bioguide_id" => cell(&cosponsor.bioguide_id),
+    "full_name" => cell(&cosponsor.full_name),
+    "first_name" => cell(&cosponsor.first_name),
+    "middle_name" => cell(&cosponsor.middle_name),
+    "last_name" => cell(&cosponsor.last_name),
+    "party" => cell(&cosponsor.party),
+    "state" => cell(&cosponsor.state),
+    "district" => cell(&cosponsor.district),
+    "is_original_cosponsor" => cell(&cosponsor.is_original_cosponsor),
+    "sponsorship_date" => cell(&cosponsor.sponsorship_date),
+    "sponsorship_withdrawn_date" => cell(&cosponsor.sponsorship_withdrawn_date),
+    "url" => cell(&cosponsor.url),
+});
+
+tabular!(RelatedBill, |related| {
+    "congress" => cell(&related.congress),
+    "type" => cell(&related.bill_type),
+    "number" => cell(&related.number),
+    "title" => cell(&related.title),
+    "relationship_details.type" => joined(related.relationship_details.iter().map(|d| &d.type_)),
+    "relationship_details.identified_by" => joined(related.relationship_details.iter().map(|d| &d.identified_by)),
+    "latest_action.action_date" => cell(&related.latest_action.action_date),
+    "latest_action.text" => cell(&related.latest_action.text),
+    "url" => cell(&related.url),
+});
+
+tabular!(LegislativeSubject, |subject| {
+    "name" => cell(&subject.name),
+});
+
+tabular!(Summary, |summary| {
+    "version_code" => cell(&summary.version_code),
+    "action_date" => cell(&summary.action_date),
+    "action_desc" => cell(&summary.action_desc),
+    "update_date" => cell(&summary.update_date),
+    "text" => cell(&summary.text),
+});
+
+tabular!(TextVersion, |version| {
+    "type" => cell(&version.version_code),
+    "date" => cell(&version.date),
+    "formats.type" => joined(version.formats.iter().map(|f| &f.format_type)),
+    "formats.url" => joined(version.formats.iter().map(|f| &f.url)),
+});
+
+tabular!(Title, |title| {
+    "title_type" => cell(&title.title_type),
+    "title" => cell(&title.title),
+    "chamber_name" => cell(&title.chamber_name),
+    "chamber_code" => cell(&title.chamber_code),
+    "bill_text_version_code" => cell(&title.bill_text_version_code),
+    "bill_text_version_name" => cell(&title.bill_text_version_name),
+});
+
+/// Somewhere to write items one at a time
+pub trait RecordWriter<T> {
+    fn write(&mut self, item: &T) -> Result<()>;
+
+    fn write_all<'a>(&mut self, items: impl IntoIterator<Item = &'a T>) -> Result<usize>
+    where
+        T: 'a,
+    {
+        let mut count = 0;
+        for item in items {
+            self.write(item)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Writes [`Tabular`] items as CSV, starting with a header row
+#[derive(Debug)]
+pub struct CsvWriter<W: Write, T> {
+    writer: csv::Writer<W>,
+    header_written: bool,
+    _item: PhantomData<T>,
+}
+
+impl<W: Write, T: Tabular> CsvWriter<W, T> {
+    pub fn new(writer: W) -> Self {
+        CsvWriter {
+            writer: csv::Writer::from_writer(writer),
+            header_written: false,
+            _item: PhantomData,
+        }
+    }
+
+    /// Flushes and returns the underlying writer
+    pub fn into_inner(mut self) -> Result<W> {
+        if !self.header_written {
+            self.writer.write_record(T::COLUMNS).context(CsvSnafu)?;
+        }
+        self.writer
+            .into_inner()
+            .map_err(|e| e.into_error())
+            .context(WriteSnafu)
+    }
+}
+
+impl<W: Write, T: Tabular> RecordWriter<T> for CsvWriter<W, T> {
+    fn write(&mut self, item: &T) -> Result<()> {
+        if !self.header_written {
+            self.writer.write_record(T::COLUMNS).context(CsvSnafu)?;
+            self.header_written = true;
+        }
+        self.writer.write_record(item.row()).context(CsvSnafu)
+    }
+}
+
+/// Writes items as JSON Lines, one JSON object per line with the API's field names
+#[derive(Debug)]
+pub struct NdjsonWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    pub fn new(writer: W) -> Self {
+        NdjsonWriter { writer }
+    }
+
+    /// Flushes and returns the underlying writer
+    pub fn into_inner(mut self) -> Result<W> {
+        self.writer.flush().context(WriteSnafu)?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write, T: Serialize> RecordWriter<T> for NdjsonWriter<W> {
+    fn write(&mut self, item: &T) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, item)
+            .map_err(std::io::Error::from)
+            .context(WriteSnafu)?;
+        self.writer.write_all(b"\n").context(WriteSnafu)
+    }
+}
+
+/// Writes the items of `first` and of every page after it, returning how many
+/// were written. Only one page is held in memory at a time.
+pub async fn export_pages<P, T, S>(client: &Client, first: P, writer: &mut S) -> Result<usize>
+where
+    P: DeserializeOwned + PagedResponse<T>,
+    S: RecordWriter<T>,
+{
+    let mut count = 0;
+    let mut page = Some(first);
+    while let Some(current) = page {
+        count += writer.write_all(current.get_items())?;
+        page = client.next(&current).await?;
+    }
+    Ok(count)
+}
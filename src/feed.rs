@@ -0,0 +1,226 @@
+//! Render [`watch`](crate::watch) events as RSS 2.0 and Atom feeds
+//!
+//! ```no_run
+//! # async fn run(client: congress::Client) -> congress::Result<()> {
+//! use congress::{bill_id::BillId, bill_type::BillType, feed::Feed, watch::Watcher};
+//!
+//! let bill = BillId::new(118, BillType::House, 3746);
+//! let mut watcher = Watcher::new(&client, [bill]);
+//! let mut feed = Feed::new("H.R. 3746", bill.web_url());
+//! feed.extend(&watcher.poll_bill(bill).await?, chrono::Utc::now());
+//! std::fs::write("hr3746.xml", feed.atom()).unwrap();
+//! # Ok(())
+//! # }
+//! ```
+use crate::watch::Event;
+use chrono::{DateTime, NaiveDate, Utc};
+use quick_xml::escape::escape;
+use std::{cmp::Reverse, fmt::Write};
+use url::Url;
+
+/// One item of a feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Stable across polls, so readers don't show an event twice
+    pub id: String,
+    pub title: String,
+    pub summary: String,
+    pub link: Url,
+    pub updated: DateTime<Utc>,
+}
+
+impl Entry {
+    /// `seen` dates the events the API gives no date for
+    pub fn new(event: &Event, seen: DateTime<Utc>) -> Self {
+        let bill = event.bill();
+        let page = |path: &str| {
+            let mut url = bill.web_url();
+            url.path_segments_mut().unwrap().push(path);
+            url
+        };
+        let (kind, key, summary, link, date) = match event {
+            Event::NewAction { action, .. } => (
+                "action",
+                format!(
+                    "{}:{}:{:016x}",
+                    action.action_date,
+                    action.action_code.as_deref().unwrap_or_default(),
+                    fnv1a(&[
+                        action.action_code.as_deref().unwrap_or_default(),
+                        &action.text,
+                        &action.source_system.name,
+                    ])
+                ),
+                action.text.clone(),
+                page("all-actions"),
+                Some(action.action_date),
+            ),
+            Event::CosponsorAdded { cosponsor, .. } => (
+                "cosponsor-added",
+                cosponsor.bioguide_id.clone(),
+                format!("{} cosponsored", cosponsor.full_name),
+                page("cosponsors"),
+                Some(cosponsor.sponsorship_date),
+            ),
+            Event::CosponsorWithdrawn { cosponsor, .. } => (
+                "cosponsor-withdrawn",
+                cosponsor.bioguide_id.clone(),
+                format!("{} withdrew as a cosponsor", cosponsor.full_name),
+                page("cosponsors"),
+                cosponsor.sponsorship_withdrawn_date,
+            ),
+            Event::NewTextVersion { version, .. } => (
+                "text",
+                format!(
+                    "{}:{}",
+                    version.version_code.code(),
+                    version.date.date_naive()
+                ),
+                format!(
+                    "New text: {}",
+                    version
+                        .version_code
+                        .name()
+                        .unwrap_or(version.version_code.code())
+                ),
+                page("text"),
+                Some(version.date.date_naive()),
+            ),
+            Event::BecameLaw { law, date, .. } => (
+                "law",
+                law.number.clone(),
+                format!("Became {} {}", law.type_, law.number),
+                bill.web_url(),
+                Some(*date),
+            ),
+        };
+
+        Entry {
+            id: format!("tag:api.congress.gov,2023:{bill}:{kind}:{key}"),
+            title: format!("{}: {summary}", bill.citation()),
+            summary,
+            link,
+            updated: date.map(midnight).unwrap_or(seen),
+        }
+    }
+}
+
+/// A hash that, unlike the std hasher, is the same on every build, so IDs
+/// stay stable across releases. Actions are keyed on the same fields
+/// [`watch`](crate::watch) matches them on, since several can share a date and code.
+fn fnv1a(parts: &[&str]) -> u64 {
+    parts.iter().fold(0xcbf29ce484222325, |hash, part| {
+        part.bytes().chain([0]).fold(hash, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        })
+    })
+}
+
+fn midnight(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0).unwrap().and_utc()
+}
+
+/// A feed of bill updates, newest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feed {
+    title: String,
+    link: Url,
+    entries: Vec<Entry>,
+}
+
+impl Feed {
+    pub fn new(title: impl Into<String>, link: Url) -> Self {
+        Feed {
+            title: title.into(),
+            link,
+            entries: vec![],
+        }
+    }
+
+    pub fn push(&mut self, entry: Entry) {
+        self.entries.push(entry);
+        self.sort();
+    }
+
+    /// Adds an entry for each event, see [`Entry::new`]
+    pub fn extend(&mut self, events: &[Event], seen: DateTime<Utc>) {
+        self.entries
+            .extend(events.iter().map(|event| Entry::new(event, seen)));
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        self.entries.sort_by_key(|e| Reverse(e.updated));
+    }
+
+    /// Drops all but the `count` newest entries
+    pub fn truncate(&mut self, count: usize) {
+        self.entries.truncate(count);
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// When the newest entry happened, or the Unix epoch for an empty feed
+    pub fn updated(&self) -> DateTime<Utc> {
+        self.entries
+            .first()
+            .map(|e| e.updated)
+            .unwrap_or(DateTime::UNIX_EPOCH)
+    }
+
+    pub fn rss(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str(r#"<rss version="2.0"><channel>"#);
+        element(&mut xml, "title", &self.title);
+        element(&mut xml, "link", self.link.as_str());
+        element(&mut xml, "description", &self.title);
+        element(&mut xml, "lastBuildDate", &self.updated().to_rfc2822());
+        for entry in &self.entries {
+            xml.push_str("<item>");
+            element(&mut xml, "title", &entry.title);
+            element(&mut xml, "link", entry.link.as_str());
+            element(&mut xml, "description", &entry.summary);
+            write!(
+                xml,
+                r#"<guid isPermaLink="false">{}</guid>"#,
+                escape(&entry.id)
+            )
+            .unwrap();
+            element(&mut xml, "pubDate", &entry.updated.to_rfc2822());
+            xml.push_str("</item>");
+        }
+        xml.push_str("</channel></rss>");
+        xml
+    }
+
+    pub fn atom(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+        element(&mut xml, "id", self.link.as_str());
+        element(&mut xml, "title", &self.title);
+        link(&mut xml, &self.link);
+        element(&mut xml, "updated", &self.updated().to_rfc3339());
+        for entry in &self.entries {
+            xml.push_str("<entry>");
+            element(&mut xml, "id", &entry.id);
+            element(&mut xml, "title", &entry.title);
+            link(&mut xml, &entry.link);
+            element(&mut xml, "updated", &entry.updated.to_rfc3339());
+            element(&mut xml, "summary", &entry.summary);
+            xml.push_str("<author><name>Congress.gov</name></author>");
+            xml.push_str("</entry>");
+        }
+        xml.push_str("</feed>");
+        xml
+    }
+}
+
+fn element(xml: &mut String, name: &str, text: &str) {
+    write!(xml, "<{name}>{}</{name}>", escape(text)).unwrap();
+}
+
+fn link(xml: &mut String, url: &Url) {
+    write!(xml, r#"<link href="{}"/>"#, escape(url.as_str())).unwrap();
+}
@@ -1,10 +1,22 @@
+use crate::status::Stage;
 use chrono::{NaiveDate, NaiveTime};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LatestAction {
     pub action_date: NaiveDate,
     pub action_time: Option<NaiveTime>,
     pub text: String,
+    /// Fields the API returned that this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl LatestAction {
+    /// The legislative stage suggested by the action text, if it can be classified
+    pub fn stage(&self) -> Option<Stage> {
+        Stage::from_text(&self.text)
+    }
 }
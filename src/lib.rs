@@ -1,29 +1,55 @@
 #![doc = include_str!("../README.md")]
+use api_key::{ApiKey, KeyRing, API_KEY_HEADER};
 use bill::BillHandler;
 use bill_type::BillType;
 use bills::BillsHandler;
 use error::{
-    ClientBuildSnafu, InvalidBaseUrlSnafu, InvalidUrlSnafu, JsonParseSnafu, ParameterSnafu,
-    ResponseSnafu, SendSnafu,
+    InvalidBaseUrlSnafu, InvalidUrlSnafu, JsonParseSnafu, ParameterEncodeSnafu, ParameterSnafu,
+    UnexpectedStatusSnafu,
 };
+use middleware::{Hook, Middleware, RequestContext, ResponseContext};
 use pagination::PagedResponse;
 use parameters::Parameters;
 use reqwest::IntoUrl;
-use serde::Serialize;
 use snafu::ResultExt;
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc, time::Instant};
+use transport::{HttpRequest, HttpResponse, HttpTransport, ReqwestTransport};
 use url::Url;
 
 pub use error::Result;
 
+pub mod action_code;
 pub mod amendment_type;
+pub mod api_key;
+pub mod bill_id;
 pub mod bill_type;
 pub mod chamber;
+#[cfg(feature = "parquet")]
+pub mod columnar;
 pub mod error;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "feed")]
+pub mod feed;
 pub mod latest_action;
+pub mod middleware;
 pub mod pagination;
 pub mod parameters;
+pub mod party;
 pub mod sort;
+pub mod state;
+pub mod status;
+#[cfg(feature = "store")]
+pub mod store;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
+pub mod version_code;
+pub mod votes;
+#[cfg(feature = "watch")]
+pub mod watch;
+#[cfg(feature = "webhook")]
+pub mod webhook;
 
 pub mod bill;
 pub mod bills;
@@ -33,17 +59,19 @@ static DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CAR
 
 #[derive(Debug)]
 pub struct Client {
-    client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
+    middleware: Vec<Arc<dyn Middleware>>,
     base_url: Url,
-    api_key: String,
+    user_agent: String,
+    api_keys: KeyRing,
 }
 
 impl Client {
-    pub fn bills(&self) -> BillsHandler {
+    pub fn bills(&self) -> BillsHandler<'_> {
         BillsHandler::new(self)
     }
 
-    pub fn bill(&self, congress: u32, bill_type: BillType, bill_number: u32) -> BillHandler {
+    pub fn bill(&self, congress: u32, bill_type: BillType, bill_number: u32) -> BillHandler<'_> {
         BillHandler::new(self, congress, bill_type, bill_number)
     }
 
@@ -61,6 +89,10 @@ impl Client {
         self.get_page(response.next()).await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "page", skip_all, fields(path = url.as_ref().map(|u| u.path())))
+    )]
     async fn get_page<T, R>(&self, url: Option<Url>) -> Result<Option<T>>
     where
         T: serde::de::DeserializeOwned + PagedResponse<R>,
@@ -78,32 +110,162 @@ impl Client {
         }
     }
 
-    pub(crate) async fn get<R, P>(&self, path: &str, params: Option<&P>) -> Result<R>
+    async fn send(&self, url: Url, context: RequestContext) -> Result<HttpResponse> {
+        self.send_request(HttpRequest::new(url), context).await
+    }
+
+    async fn send_with_key(
+        &self,
+        url: Url,
+        context: RequestContext,
+        key: &ApiKey,
+    ) -> Result<HttpResponse> {
+        let request = HttpRequest::new(url).header(API_KEY_HEADER, key.expose());
+        self.send_request(request, context).await
+    }
+
+    async fn send_request(
+        &self,
+        request: HttpRequest,
+        mut context: RequestContext,
+    ) -> Result<HttpResponse> {
+        for middleware in &self.middleware {
+            middleware.before_request(&mut context);
+        }
+
+        let mut request = request.header("User-Agent", &self.user_agent);
+        request.headers.extend(context.headers.iter().cloned());
+
+        let start = Instant::now();
+        let response = self.transport.get(request).await;
+        let completed = ResponseContext {
+            request: &context,
+            status: response.as_ref().ok().map(|r| r.status),
+            elapsed: start.elapsed(),
+            bytes: response.as_ref().map(|r| r.body.len()).unwrap_or_default(),
+        };
+        for middleware in self.middleware.iter().rev() {
+            middleware.after_response(&completed);
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("bytes", completed.bytes);
+            match completed.status {
+                Some(status) => {
+                    span.record("status", status);
+                    tracing::debug!(status, bytes = completed.bytes, elapsed = ?completed.elapsed, "response");
+                }
+                None => tracing::warn!(elapsed = ?completed.elapsed, "request failed"),
+            }
+        }
+
+        response
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "download", skip_all, fields(url = %url, status, bytes))
+    )]
+    pub(crate) async fn get_document(&self, url: Url) -> Result<Vec<u8>> {
+        let context = RequestContext::new(url.as_str(), None);
+        let response = self.send(url.clone(), context).await?;
+        snafu::ensure!(
+            response.is_success(),
+            UnexpectedStatusSnafu {
+                status: response.status,
+                url
+            }
+        );
+        Ok(response.body)
+    }
+
+    // The API key is only ever sent as a header, which is never recorded
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "get",
+            skip_all,
+            fields(
+                endpoint = path,
+                offset = params.and_then(|p| p.offset),
+                limit = params.and_then(|p| p.limit),
+                status,
+                bytes,
+                attempts = tracing::field::Empty,
+            )
+        )
+    )]
+    pub(crate) async fn get<R>(&self, path: &str, params: Option<&Parameters>) -> Result<R>
     where
-        P: Serialize + ?Sized + Debug,
         R: serde::de::DeserializeOwned,
     {
-        let url = self.base_url.join(path).context(InvalidUrlSnafu)?;
-        let mut request = self.client.get(url).query(&[
-            ("api_key", &self.api_key),
-            ("format", &String::from("json")),
-        ]);
+        let body = self.get_body(path, params, "json").await?;
+        let text = String::from_utf8_lossy(&body);
+        let de = &mut serde_json::Deserializer::from_str(&text);
+        serde_path_to_error::deserialize(de).context(JsonParseSnafu { path })
+    }
+
+    /// Requests `path` with `format=xml` and returns the body as is
+    #[cfg(feature = "xml")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "get",
+            skip_all,
+            fields(
+                endpoint = path,
+                format = "xml",
+                offset = params.and_then(|p| p.offset),
+                limit = params.and_then(|p| p.limit),
+                status,
+                bytes,
+                attempts = tracing::field::Empty,
+            )
+        )
+    )]
+    pub(crate) async fn get_xml(&self, path: &str, params: Option<&Parameters>) -> Result<Vec<u8>> {
+        self.get_body(path, params, "xml").await
+    }
+
+    async fn get_body(
+        &self,
+        path: &str,
+        params: Option<&Parameters>,
+        format: &str,
+    ) -> Result<Vec<u8>> {
+        let mut url = self.base_url.join(path).context(InvalidUrlSnafu)?;
+        url.query_pairs_mut().append_pair("format", format);
         if let Some(p) = params {
-            request = request.query(p);
+            let query = serde_urlencoded::to_string(p).context(ParameterEncodeSnafu)?;
+            if !query.is_empty() {
+                let query = format!("{}&{query}", url.query().unwrap_or_default());
+                url.set_query(Some(&query));
+            }
         }
 
-        let response = request.send().await.context(SendSnafu)?;
-        let is_success = response.status().is_success();
-        let text = response.text().await.context(ResponseSnafu)?;
-        let de = &mut serde_json::Deserializer::from_str(&text);
-
-        if is_success {
-            Ok(serde_path_to_error::deserialize(de).context(JsonParseSnafu)?)
-        } else {
-            Err(crate::error::Error::Congress {
-                source: serde_path_to_error::deserialize(de).context(JsonParseSnafu)?,
-            })
+        // On a 429 the key has hit its hourly quota, so retry with the next one
+        let mut attempts = 0;
+        let response = loop {
+            attempts += 1;
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("attempts", attempts);
+            let (index, key) = self.api_keys.current();
+            let response = self
+                .send_with_key(url.clone(), RequestContext::new(path, params), key)
+                .await?;
+            if response.status != 429 || attempts >= self.api_keys.len() {
+                break response;
+            }
+            self.api_keys.rotate(index);
+        };
+        if !response.is_success() {
+            return Err(error::Error::from_response(path, &response));
         }
+        Ok(response.body)
     }
 }
 
@@ -111,18 +273,36 @@ impl Client {
 pub struct ClientBuilder {
     base_url: Url,
     user_agent: String,
-    api_key: String,
+    api_keys: Vec<ApiKey>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    middleware: Vec<Arc<dyn Middleware>>,
 }
 
 impl ClientBuilder {
-    pub fn new(api_key: impl Into<String>) -> ClientBuilder {
+    pub fn new(api_key: impl Into<ApiKey>) -> ClientBuilder {
         ClientBuilder {
             base_url: Url::parse(DEFAULT_BASE_URL).unwrap(),
             user_agent: DEFAULT_USER_AGENT.into(),
-            api_key: api_key.into(),
+            api_keys: vec![api_key.into()],
+            transport: None,
+            middleware: vec![],
         }
     }
 
+    /// Reads the key from the `CONGRESS_API_KEY` environment variable. Several
+    /// comma separated keys are rotated through as each hits its quota.
+    pub fn from_env() -> Result<ClientBuilder> {
+        let mut keys = api_key::keys_from_env()?.into_iter();
+        let builder = ClientBuilder::new(keys.next().expect("at least one key"));
+        Ok(keys.fold(builder, ClientBuilder::fallback_api_key))
+    }
+
+    /// Adds a key to switch to when the previous one hits its hourly quota
+    pub fn fallback_api_key(mut self, api_key: impl Into<ApiKey>) -> Self {
+        self.api_keys.push(api_key.into());
+        self
+    }
+
     pub fn base_url(mut self, base_url: impl IntoUrl) -> Result<Self> {
         self.base_url = base_url.into_url().context(InvalidBaseUrlSnafu)?;
         Ok(self)
@@ -133,16 +313,46 @@ impl ClientBuilder {
         self
     }
 
+    /// Sends requests through the given transport instead of the default [`ReqwestTransport`]
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Adds a middleware that observes or adjusts every request
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Runs `hook` before every request is sent, e.g. to add a header
+    pub fn before_request<F>(self, hook: F) -> Self
+    where
+        F: Fn(&mut RequestContext) + Send + Sync + 'static,
+    {
+        self.middleware(Hook::Before(Box::new(hook)))
+    }
+
+    /// Runs `hook` after every response is received, e.g. to record latency
+    pub fn after_response<F>(self, hook: F) -> Self
+    where
+        F: Fn(&ResponseContext) + Send + Sync + 'static,
+    {
+        self.middleware(Hook::After(Box::new(hook)))
+    }
+
     pub fn build(&self) -> Result<Client> {
-        let client = reqwest::ClientBuilder::new()
-            .user_agent(self.user_agent.clone())
-            .build()
-            .context(ClientBuildSnafu)?;
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(ReqwestTransport::new()?),
+        };
 
         Ok(Client {
-            client,
+            transport,
+            middleware: self.middleware.clone(),
             base_url: self.base_url.clone(),
-            api_key: self.api_key.clone(),
+            user_agent: self.user_agent.clone(),
+            api_keys: KeyRing::new(self.api_keys.clone()),
         })
     }
 }
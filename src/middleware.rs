@@ -0,0 +1,92 @@
+use crate::parameters::Parameters;
+use std::{fmt::Debug, time::Duration};
+
+/// An outgoing request as seen by [`Middleware::before_request`]
+///
+/// The API key is never part of the context.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestContext {
+    /// The API path, e.g. `/v3/bill/118/hr/1`, or the full URL of a downloaded document
+    pub path: String,
+    pub parameters: Option<Parameters>,
+    /// Headers to send with the request, in addition to the client's own
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestContext {
+    pub(crate) fn new(path: impl Into<String>, parameters: Option<&Parameters>) -> Self {
+        RequestContext {
+            path: path.into(),
+            parameters: parameters.copied(),
+            headers: vec![],
+        }
+    }
+
+    /// Adds a header to the request, e.g. a correlation ID
+    pub fn insert_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.headers.push((name.into(), value.into()));
+    }
+
+    /// The value of a header added by an earlier middleware
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A completed request as seen by [`Middleware::after_response`]
+#[derive(Debug)]
+pub struct ResponseContext<'a> {
+    pub request: &'a RequestContext,
+    /// The HTTP status, or `None` if the transport failed
+    pub status: Option<u16>,
+    pub elapsed: Duration,
+    /// Size of the response body in bytes
+    pub bytes: usize,
+}
+
+/// Observes or adjusts every request a [`Client`](crate::Client) makes
+///
+/// `before_request` hooks run in the order the middleware was added and
+/// `after_response` hooks run in reverse, so the first middleware wraps all
+/// the others.
+pub trait Middleware: Debug + Send + Sync {
+    fn before_request(&self, _request: &mut RequestContext) {}
+
+    fn after_response(&self, _response: &ResponseContext) {}
+}
+
+type BeforeFn = dyn Fn(&mut RequestContext) + Send + Sync;
+type AfterFn = dyn Fn(&ResponseContext) + Send + Sync;
+
+/// Middleware built from closures by [`ClientBuilder::before_request`](crate::ClientBuilder::before_request)
+/// and [`ClientBuilder::after_response`](crate::ClientBuilder::after_response)
+pub(crate) enum Hook {
+    Before(Box<BeforeFn>),
+    After(Box<AfterFn>),
+}
+
+impl Debug for Hook {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Before(_) => write!(f, "Hook::Before"),
+            Self::After(_) => write!(f, "Hook::After"),
+        }
+    }
+}
+
+impl Middleware for Hook {
+    fn before_request(&self, request: &mut RequestContext) {
+        if let Self::Before(hook) = self {
+            hook(request)
+        }
+    }
+
+    fn after_response(&self, response: &ResponseContext) {
+        if let Self::After(hook) = self {
+            hook(response)
+        }
+    }
+}
@@ -3,10 +3,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-#[derive(Default, Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameters {
     pub limit: Option<u32>,
-    offset: Option<u32>,
+    pub offset: Option<u32>,
     #[serde(
         rename(serialize = "fromDateTime"),
         serialize_with = "date_serialize",
@@ -51,6 +51,7 @@ pub trait PageParameters: HasParameters + Sized {
 }
 
 pub trait SortParameters: HasParameters + Sized {
+    #[allow(clippy::wrong_self_convention)]
     fn from_date(mut self, from_date: DateTime<Utc>) -> Self {
         self.get_parameters().from_date = Some(from_date);
         self
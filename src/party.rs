@@ -0,0 +1,83 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! parties {
+    ($($variant:ident, $code:literal, $name:literal $(| $alias:literal)*;)+) => {
+        /// A member's party
+        ///
+        /// Parties this crate doesn't know about are kept in [`Party::Other`].
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Party {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+            Other(String),
+        }
+
+        impl Party {
+            /// The code Congress.gov and the roll call votes use, e.g. `ID`
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)+
+                    Self::Other(code) => code,
+                }
+            }
+
+            /// The human readable name, if the party is known
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($name),)+
+                    Self::Other(_) => None,
+                }
+            }
+        }
+
+        impl From<&str> for Party {
+            /// Reads either the code (`D`) or the name (`Democratic`), ignoring case
+            fn from(party: &str) -> Self {
+                let party = party.trim();
+                $(
+                    if party.eq_ignore_ascii_case($code)
+                        || party.eq_ignore_ascii_case($name)
+                        $(|| party.eq_ignore_ascii_case($alias))*
+                    {
+                        return Self::$variant;
+                    }
+                )+
+                Self::Other(party.to_string())
+            }
+        }
+    };
+}
+
+parties! {
+    Democratic, "D", "Democratic" | "Democrat";
+    Republican, "R", "Republican";
+    Independent, "I", "Independent";
+    IndependentDemocrat, "ID", "Independent Democrat";
+    Libertarian, "L", "Libertarian";
+}
+
+impl std::fmt::Display for Party {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Party {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Party::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+impl Serialize for Party {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
@@ -14,7 +14,7 @@ where
     serializer.serialize_str(&s)
 }
 
-#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
 pub enum Sort {
     /// Sort by the item's update date in ascending order
     UpdateDateAscending,
@@ -0,0 +1,232 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+macro_rules! states {
+    ($($variant:ident, $code:literal, $name:literal, $fips:literal;)+) => {
+        /// A state, the District of Columbia or a territory with a member of Congress
+        ///
+        /// Variants are declared, and therefore ordered, by their postal code.
+        /// Codes this crate doesn't know about are kept in [`State::Other`] and sort last.
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum State {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+            Other(String),
+        }
+
+        impl State {
+            /// Every state, the District of Columbia and the territories
+            pub const ALL: &'static [State] = &[$(Self::$variant),+];
+
+            /// The postal code, e.g. `NJ`, or the value as the API returned it if unknown
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)+
+                    Self::Other(code) => code,
+                }
+            }
+
+            /// The full name, e.g. `New Jersey`, if the state is known
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($name),)+
+                    Self::Other(_) => None,
+                }
+            }
+
+            /// The two digit FIPS code, e.g. `34` for New Jersey, if the state is known
+            pub fn fips(&self) -> Option<u8> {
+                match self {
+                    $(Self::$variant => Some($fips),)+
+                    Self::Other(_) => None,
+                }
+            }
+        }
+    };
+}
+
+states! {
+    Alaska, "AK", "Alaska", 2;
+    Alabama, "AL", "Alabama", 1;
+    Arkansas, "AR", "Arkansas", 5;
+    AmericanSamoa, "AS", "American Samoa", 60;
+    Arizona, "AZ", "Arizona", 4;
+    California, "CA", "California", 6;
+    Colorado, "CO", "Colorado", 8;
+    Connecticut, "CT", "Connecticut", 9;
+    DistrictOfColumbia, "DC", "District of Columbia", 11;
+    Delaware, "DE", "Delaware", 10;
+    Florida, "FL", "Florida", 12;
+    Georgia, "GA", "Georgia", 13;
+    Guam, "GU", "Guam", 66;
+    Hawaii, "HI", "Hawaii", 15;
+    Iowa, "IA", "Iowa", 19;
+    Idaho, "ID", "Idaho", 16;
+    Illinois, "IL", "Illinois", 17;
+    Indiana, "IN", "Indiana", 18;
+    Kansas, "KS", "Kansas", 20;
+    Kentucky, "KY", "Kentucky", 21;
+    Louisiana, "LA", "Louisiana", 22;
+    Massachusetts, "MA", "Massachusetts", 25;
+    Maryland, "MD", "Maryland", 24;
+    Maine, "ME", "Maine", 23;
+    Michigan, "MI", "Michigan", 26;
+    Minnesota, "MN", "Minnesota", 27;
+    Missouri, "MO", "Missouri", 29;
+    NorthernMarianaIslands, "MP", "Northern Mariana Islands", 69;
+    Mississippi, "MS", "Mississippi", 28;
+    Montana, "MT", "Montana", 30;
+    NorthCarolina, "NC", "North Carolina", 37;
+    NorthDakota, "ND", "North Dakota", 38;
+    Nebraska, "NE", "Nebraska", 31;
+    NewHampshire, "NH", "New Hampshire", 33;
+    NewJersey, "NJ", "New Jersey", 34;
+    NewMexico, "NM", "New Mexico", 35;
+    Nevada, "NV", "Nevada", 32;
+    NewYork, "NY", "New York", 36;
+    Ohio, "OH", "Ohio", 39;
+    Oklahoma, "OK", "Oklahoma", 40;
+    Oregon, "OR", "Oregon", 41;
+    Pennsylvania, "PA", "Pennsylvania", 42;
+    PuertoRico, "PR", "Puerto Rico", 72;
+    RhodeIsland, "RI", "Rhode Island", 44;
+    SouthCarolina, "SC", "South Carolina", 45;
+    SouthDakota, "SD", "South Dakota", 46;
+    Tennessee, "TN", "Tennessee", 47;
+    Texas, "TX", "Texas", 48;
+    Utah, "UT", "Utah", 49;
+    Virginia, "VA", "Virginia", 51;
+    VirginIslands, "VI", "Virgin Islands", 78;
+    Vermont, "VT", "Vermont", 50;
+    Washington, "WA", "Washington", 53;
+    Wisconsin, "WI", "Wisconsin", 55;
+    WestVirginia, "WV", "West Virginia", 54;
+    Wyoming, "WY", "Wyoming", 56;
+}
+
+impl State {
+    /// Whether this is one of the 50 states, which have senators and voting
+    /// representatives. The District of Columbia and the territories elect a
+    /// non-voting delegate, or for Puerto Rico a resident commissioner.
+    pub fn is_state(&self) -> bool {
+        !matches!(
+            self,
+            Self::DistrictOfColumbia
+                | Self::PuertoRico
+                | Self::Guam
+                | Self::VirginIslands
+                | Self::AmericanSamoa
+                | Self::NorthernMarianaIslands
+                | Self::Other(_)
+        )
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for State {
+    type Err = String;
+
+    /// Parses either the code (`NJ`) or the name (`New Jersey`), ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .iter()
+            .find(|v| {
+                v.code().eq_ignore_ascii_case(s)
+                    || v.name().is_some_and(|n| n.eq_ignore_ascii_case(s))
+            })
+            .cloned()
+            .ok_or_else(|| format!("unknown state: {s}"))
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let state = String::deserialize(deserializer)?;
+        Ok(state.parse().unwrap_or(Self::Other(state)))
+    }
+}
+
+impl Serialize for State {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// A House member's district. The API numbers the seat of a state with a single
+/// representative, and of the District of Columbia and the territories, `0`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum District {
+    AtLarge,
+    Numbered(u32),
+}
+
+impl District {
+    pub fn is_at_large(&self) -> bool {
+        matches!(self, Self::AtLarge)
+    }
+
+    /// The district number, or `None` for an at-large seat
+    pub fn number(&self) -> Option<u32> {
+        match self {
+            Self::AtLarge => None,
+            Self::Numbered(number) => Some(*number),
+        }
+    }
+}
+
+impl From<u32> for District {
+    fn from(number: u32) -> Self {
+        match number {
+            0 => Self::AtLarge,
+            number => Self::Numbered(number),
+        }
+    }
+}
+
+/// The API's numbering, with `0` for an at-large seat
+impl From<District> for u32 {
+    fn from(district: District) -> Self {
+        district.number().unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for District {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AtLarge => write!(f, "at-large"),
+            Self::Numbered(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for District {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u32::deserialize(deserializer).map(District::from)
+    }
+}
+
+impl Serialize for District {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(u32::from(*self))
+    }
+}
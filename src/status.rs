@@ -0,0 +1,195 @@
+use crate::{
+    action_code::{ActionCategory, ActionCodeInfo},
+    bill::actions::{Action, ActionType, SourceSystemCode},
+    chamber::Chamber,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// A normalized legislative stage
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Introduced,
+    Referred,
+    Reported,
+    PassedHouse,
+    PassedSenate,
+    ResolvingDifferences,
+    ToPresident,
+    Vetoed,
+    BecameLaw,
+}
+
+impl Stage {
+    /// How far along the legislative process the stage is. Passage in either
+    /// chamber ranks the same since bills may originate in either.
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Introduced => 0,
+            Self::Referred => 1,
+            Self::Reported => 2,
+            Self::PassedHouse | Self::PassedSenate => 3,
+            Self::ResolvingDifferences => 4,
+            Self::ToPresident => 5,
+            Self::Vetoed => 6,
+            Self::BecameLaw => 7,
+        }
+    }
+
+    /// Classifies a Library of Congress action code by its entry in the
+    /// [action code dictionary](ActionCodeInfo)
+    pub fn from_action_code(code: &str) -> Option<Stage> {
+        ActionCodeInfo::lookup(code).and_then(Self::from_code_info)
+    }
+
+    /// The stage an action code's category marks, if any. Passage needs the
+    /// code's chamber to tell the House from the Senate.
+    pub fn from_code_info(info: &ActionCodeInfo) -> Option<Stage> {
+        match info.category {
+            ActionCategory::Introduction => Some(Self::Introduced),
+            ActionCategory::Referral => Some(Self::Referred),
+            ActionCategory::Reported => Some(Self::Reported),
+            ActionCategory::Passage => match info.chamber {
+                Some(Chamber::House) => Some(Self::PassedHouse),
+                Some(Chamber::Senate) => Some(Self::PassedSenate),
+                None => None,
+            },
+            ActionCategory::ResolvingDifferences => Some(Self::ResolvingDifferences),
+            ActionCategory::President => Some(Self::ToPresident),
+            ActionCategory::Veto => Some(Self::Vetoed),
+            ActionCategory::Law => Some(Self::BecameLaw),
+            ActionCategory::Committee | ActionCategory::Calendar | ActionCategory::Floor => None,
+        }
+    }
+
+    /// Classifies free action text, such as [`LatestAction::text`](crate::latest_action::LatestAction)
+    pub fn from_text(text: &str) -> Option<Stage> {
+        let text = text.to_lowercase();
+        let has = |needle: &str| text.contains(needle);
+
+        if has("became public law") || has("became private law") {
+            Some(Self::BecameLaw)
+        } else if has("vetoed by president") || has("pocket vetoed") {
+            Some(Self::Vetoed)
+        } else if has("presented to president") || has("signed by president") {
+            Some(Self::ToPresident)
+        } else if has("resolving differences")
+            || has("agreed to house amendment")
+            || has("agreed to senate amendment")
+            || has("conference report")
+        {
+            Some(Self::ResolvingDifferences)
+        } else if has("passed senate") || has("passed/agreed to in senate") {
+            Some(Self::PassedSenate)
+        } else if has("passed house") || has("passed/agreed to in house") {
+            Some(Self::PassedHouse)
+        } else if has("reported by") || has("reported to") || has("ordered to be reported") {
+            Some(Self::Reported)
+        } else if has("referred to") {
+            Some(Self::Referred)
+        } else if has("introduced") {
+            Some(Self::Introduced)
+        } else {
+            None
+        }
+    }
+
+    /// Classifies an action by code first, then by type, chamber and text
+    pub fn from_action(action: &Action) -> Option<Stage> {
+        if let Some(stage) = action
+            .action_code
+            .as_deref()
+            .and_then(Self::from_action_code)
+        {
+            return Some(stage);
+        }
+        match action.action_type {
+            ActionType::BecameLaw => Some(Self::BecameLaw),
+            ActionType::Floor if action.text.starts_with("On passage Passed") => {
+                match action.source_system.code {
+                    Some(SourceSystemCode::Senate) => Some(Self::PassedSenate),
+                    _ => Some(Self::PassedHouse),
+                }
+            }
+            _ => Self::from_text(&action.text),
+        }
+    }
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Introduced => write!(f, "Introduced"),
+            Self::Referred => write!(f, "Referred"),
+            Self::Reported => write!(f, "Reported"),
+            Self::PassedHouse => write!(f, "Passed House"),
+            Self::PassedSenate => write!(f, "Passed Senate"),
+            Self::ResolvingDifferences => write!(f, "Resolving Differences"),
+            Self::ToPresident => write!(f, "To President"),
+            Self::Vetoed => write!(f, "Vetoed"),
+            Self::BecameLaw => write!(f, "Became Law"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Transition {
+    pub stage: Stage,
+    pub date: NaiveDate,
+    pub action_code: Option<String>,
+    /// The text of the action that moved the bill into the stage
+    pub text: String,
+}
+
+/// The stage a bill has reached and how it got there
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    pub stage: Stage,
+    pub timeline: Vec<Transition>,
+}
+
+impl Status {
+    /// Derives the status from a bill's actions, in any order. Returns `None`
+    /// if none of the actions can be classified.
+    pub fn from_actions<'a, I>(actions: I) -> Option<Status>
+    where
+        I: IntoIterator<Item = &'a Action>,
+    {
+        let mut actions: Vec<&Action> = actions.into_iter().collect();
+        // The API lists actions newest first; a stable sort keeps same-day actions in order
+        actions.reverse();
+        actions.sort_by_key(|a| a.action_date);
+
+        let mut timeline: Vec<Transition> = vec![];
+        for action in actions {
+            let Some(stage) = Stage::from_action(action) else {
+                continue;
+            };
+            let current = timeline.last().map(|t| t.stage.rank()).unwrap_or(0);
+            let reached = timeline.iter().any(|t| t.stage == stage);
+            if !reached && stage.rank() >= current {
+                timeline.push(Transition {
+                    stage,
+                    date: action.action_date,
+                    action_code: action.action_code.clone(),
+                    text: action.text.clone(),
+                });
+            }
+        }
+
+        Some(Status {
+            stage: timeline.last()?.stage,
+            timeline,
+        })
+    }
+
+    /// The date the bill entered the given stage, if it did
+    pub fn reached(&self, stage: Stage) -> Option<NaiveDate> {
+        self.timeline
+            .iter()
+            .find(|t| t.stage == stage)
+            .map(|t| t.date)
+    }
+}
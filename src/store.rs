@@ -0,0 +1,560 @@
+//! A local SQLite mirror of the API
+//!
+//! [`Store`] creates a normalized schema with one table per resource, keyed by
+//! the bill's `(congress, bill_type, number)`, and upserts typed responses into
+//! it so the data can be queried offline with SQL. Dates are stored as ISO 8601
+//! text, timestamps as RFC 3339 text in UTC and enums by the names the API uses.
+//!
+//! The bill list only gives the day a bill was updated, while the bill detail
+//! gives the time too. Upserting a list keeps the stored time when the day
+//! hasn't changed, so `update_date` stays precise enough for incremental sync.
+//!
+//! Child rows reference their bill, so upsert a bill before its actions,
+//! cosponsors and so on. Each `replace_*` method deletes the bill's rows in
+//! its tables before inserting the new ones, so pass every page at once:
+//! rows the API no longer returns, such as a removed cosponsor, are dropped
+//! from the mirror rather than kept forever.
+//!
+//! The `checkpoints` table records how far an incremental sync got, e.g. the
+//! latest `updateDate` seen, so the next run can resume from there.
+//!
+//! ```no_run
+//! # async fn run(client: congress::Client) -> congress::Result<()> {
+//! use congress::{prelude::*, store::Store};
+//!
+//! let mut store = Store::open("congress.db")?;
+//! let bills = client.bills().congress(118).limit(250).send().await?;
+//! store.upsert_bills(&bills.bills)?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    bill::{
+        actions::Action, committees::Committee, cosponsors::Cosponsor, related_bills::RelatedBill,
+        subjects::SubjectsResponse, summaries::Summary, titles::Title, Bill,
+    },
+    bill_id::BillId,
+    bills,
+    error::StoreSnafu,
+    Result,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde::Serialize;
+use snafu::ResultExt;
+use std::path::Path;
+
+const SCHEMA: &str = r#"
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE IF NOT EXISTS bills (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    origin_chamber TEXT NOT NULL,
+    introduced_date TEXT,
+    policy_area TEXT,
+    latest_action_date TEXT NOT NULL,
+    latest_action_text TEXT NOT NULL,
+    update_date TEXT NOT NULL,
+    update_date_including_text TEXT NOT NULL,
+    PRIMARY KEY (congress, bill_type, number)
+);
+
+CREATE TABLE IF NOT EXISTS actions (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    action_date TEXT NOT NULL,
+    source_system TEXT NOT NULL,
+    text TEXT NOT NULL,
+    source_system_code INTEGER,
+    action_code TEXT,
+    action_type TEXT NOT NULL,
+    PRIMARY KEY (congress, bill_type, number, position),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS cosponsors (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    bioguide_id TEXT NOT NULL,
+    full_name TEXT NOT NULL,
+    party TEXT NOT NULL,
+    state TEXT NOT NULL,
+    district INTEGER,
+    is_original_cosponsor INTEGER NOT NULL,
+    sponsorship_date TEXT NOT NULL,
+    sponsorship_withdrawn_date TEXT,
+    PRIMARY KEY (congress, bill_type, number, bioguide_id),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS committees (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    system_code TEXT NOT NULL,
+    name TEXT NOT NULL,
+    chamber TEXT NOT NULL,
+    PRIMARY KEY (congress, bill_type, number, system_code),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS committee_activities (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    system_code TEXT NOT NULL,
+    name TEXT NOT NULL,
+    date TEXT NOT NULL,
+    PRIMARY KEY (congress, bill_type, number, system_code, name, date),
+    FOREIGN KEY (congress, bill_type, number, system_code)
+        REFERENCES committees ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS subjects (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (congress, bill_type, number, name),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS summaries (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    version_code TEXT NOT NULL,
+    action_date TEXT NOT NULL,
+    action_desc TEXT NOT NULL,
+    update_date TEXT NOT NULL,
+    text TEXT NOT NULL,
+    PRIMARY KEY (congress, bill_type, number, version_code, action_date),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS titles (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    title_type TEXT NOT NULL,
+    title TEXT NOT NULL,
+    chamber TEXT,
+    bill_text_version_code TEXT,
+    PRIMARY KEY (congress, bill_type, number, title_type, title),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS related_bills (
+    congress INTEGER NOT NULL,
+    bill_type TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    related_congress INTEGER NOT NULL,
+    related_bill_type TEXT NOT NULL,
+    related_number INTEGER NOT NULL,
+    relationship TEXT NOT NULL,
+    identified_by TEXT NOT NULL,
+    PRIMARY KEY (
+        congress, bill_type, number,
+        related_congress, related_bill_type, related_number,
+        relationship, identified_by
+    ),
+    FOREIGN KEY (congress, bill_type, number) REFERENCES bills ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS checkpoints (
+    name TEXT PRIMARY KEY,
+    position TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+"#;
+
+/// Deletes the rows of `table` that belong to `bill`
+fn delete_children(tx: &Transaction, table: &str, bill: &BillId) -> rusqlite::Result<()> {
+    tx.execute(
+        &format!("DELETE FROM {table} WHERE congress = ?1 AND bill_type = ?2 AND number = ?3"),
+        params![bill.congress, name(&bill.bill_type), bill.number],
+    )?;
+    Ok(())
+}
+
+/// A timestamp as RFC 3339 text, e.g. `2023-07-18T16:15:27Z`
+fn timestamp(value: &DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// The name the API uses for an enum value, e.g. `HR` or `House`
+fn name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// A SQLite database holding bills and their parts
+#[derive(Debug)]
+pub struct Store {
+    connection: Connection,
+}
+
+impl Store {
+    /// Opens or creates the database at `path` and creates any missing tables
+    pub fn open(path: impl AsRef<Path>) -> Result<Store> {
+        Self::from_connection(Connection::open(path).context(StoreSnafu)?)
+    }
+
+    pub fn open_in_memory() -> Result<Store> {
+        Self::from_connection(Connection::open_in_memory().context(StoreSnafu)?)
+    }
+
+    fn from_connection(connection: Connection) -> Result<Store> {
+        connection.execute_batch(SCHEMA).context(StoreSnafu)?;
+        Ok(Store { connection })
+    }
+
+    /// The underlying connection, for queries
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    fn transaction<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&Transaction) -> rusqlite::Result<()>,
+    {
+        let tx = self.connection.transaction().context(StoreSnafu)?;
+        f(&tx).context(StoreSnafu)?;
+        tx.commit().context(StoreSnafu)
+    }
+
+    /// Upserts bills from a bill list. Fields only the bill detail has are kept.
+    pub fn upsert_bills(&mut self, bills: &[bills::Bill]) -> Result<()> {
+        let ids = bills
+            .iter()
+            .map(bills::Bill::id)
+            .collect::<Result<Vec<_>>>()?;
+        self.transaction(|tx| {
+            let mut statement = tx.prepare_cached(
+                "INSERT INTO bills (congress, bill_type, number, title, origin_chamber,
+                    latest_action_date, latest_action_text, update_date, update_date_including_text)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 ON CONFLICT DO UPDATE SET
+                    title = excluded.title,
+                    origin_chamber = excluded.origin_chamber,
+                    latest_action_date = excluded.latest_action_date,
+                    latest_action_text = excluded.latest_action_text,
+                    update_date = CASE
+                        WHEN substr(bills.update_date, 1, 10) = excluded.update_date
+                        THEN bills.update_date
+                        ELSE excluded.update_date
+                    END,
+                    update_date_including_text = excluded.update_date_including_text",
+            )?;
+            for (bill, id) in bills.iter().zip(ids) {
+                statement.execute(params![
+                    id.congress,
+                    name(&id.bill_type),
+                    id.number,
+                    bill.title,
+                    name(&bill.origin_chamber),
+                    bill.latest_action.action_date,
+                    bill.latest_action.text,
+                    bill.update_date,
+                    timestamp(&bill.update_date_including_text),
+                ])?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Upserts the full details of a bill
+    pub fn upsert_bill(&mut self, bill: &Bill) -> Result<()> {
+        let id = bill.id()?;
+        self.transaction(|tx| {
+            tx.execute(
+                "INSERT INTO bills (congress, bill_type, number, title, origin_chamber,
+                    introduced_date, policy_area, latest_action_date, latest_action_text,
+                    update_date, update_date_including_text)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+                 ON CONFLICT DO UPDATE SET
+                    title = excluded.title,
+                    origin_chamber = excluded.origin_chamber,
+                    introduced_date = excluded.introduced_date,
+                    policy_area = excluded.policy_area,
+                    latest_action_date = excluded.latest_action_date,
+                    latest_action_text = excluded.latest_action_text,
+                    update_date = excluded.update_date,
+                    update_date_including_text = excluded.update_date_including_text",
+                params![
+                    id.congress,
+                    name(&id.bill_type),
+                    id.number,
+                    bill.title,
+                    name(&bill.origin_chamber),
+                    bill.introduced_date,
+                    bill.policy_area.as_ref().map(|p| &p.name),
+                    bill.latest_action.action_date,
+                    bill.latest_action.text,
+                    timestamp(&bill.update_date),
+                    timestamp(&bill.update_date_including_text),
+                ],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Actions have no ID and any of their fields can be edited upstream, so
+    /// they are keyed by their position in `actions`, newest first as the API lists them
+    pub fn replace_actions(&mut self, bill: &BillId, actions: &[Action]) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "actions", bill)?;
+            let mut statement = tx.prepare_cached(
+                "INSERT INTO actions (congress, bill_type, number, position, action_date,
+                    source_system, text, source_system_code, action_code, action_type)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            )?;
+            for (position, action) in actions.iter().enumerate() {
+                statement.execute(params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    position,
+                    action.action_date,
+                    action.source_system.name,
+                    action.text,
+                    action.source_system.code.map(u32::from),
+                    action.action_code,
+                    name(&action.action_type),
+                ])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn replace_cosponsors(&mut self, bill: &BillId, cosponsors: &[Cosponsor]) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "cosponsors", bill)?;
+            let mut statement = tx.prepare_cached(
+                "INSERT INTO cosponsors (congress, bill_type, number, bioguide_id, full_name,
+                    party, state, district, is_original_cosponsor, sponsorship_date,
+                    sponsorship_withdrawn_date)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+                 ON CONFLICT DO UPDATE SET
+                    full_name = excluded.full_name,
+                    party = excluded.party,
+                    state = excluded.state,
+                    district = excluded.district,
+                    is_original_cosponsor = excluded.is_original_cosponsor,
+                    sponsorship_date = excluded.sponsorship_date,
+                    sponsorship_withdrawn_date = excluded.sponsorship_withdrawn_date",
+            )?;
+            for cosponsor in cosponsors {
+                statement.execute(params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    cosponsor.bioguide_id,
+                    cosponsor.full_name,
+                    cosponsor.party.code(),
+                    cosponsor.state.code(),
+                    cosponsor.district.map(u32::from),
+                    cosponsor.is_original_cosponsor,
+                    cosponsor.sponsorship_date,
+                    cosponsor.sponsorship_withdrawn_date,
+                ])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn replace_committees(&mut self, bill: &BillId, committees: &[Committee]) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "committees", bill)?;
+            let mut committee_statement = tx.prepare_cached(
+                "INSERT INTO committees (congress, bill_type, number, system_code, name, chamber)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT DO UPDATE SET name = excluded.name, chamber = excluded.chamber",
+            )?;
+            let mut activity_statement = tx.prepare_cached(
+                "INSERT OR IGNORE INTO committee_activities
+                    (congress, bill_type, number, system_code, name, date)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for committee in committees {
+                committee_statement.execute(params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    committee.committee.system_code,
+                    committee.committee.name,
+                    name(&committee.chamber),
+                ])?;
+                for activity in &committee.activities {
+                    activity_statement.execute(params![
+                        bill.congress,
+                        name(&bill.bill_type),
+                        bill.number,
+                        committee.committee.system_code,
+                        activity.name,
+                        timestamp(&activity.date),
+                    ])?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Replaces the legislative subjects and sets the bill's policy area
+    pub fn replace_subjects(&mut self, bill: &BillId, subjects: &SubjectsResponse) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "subjects", bill)?;
+            tx.execute(
+                "UPDATE bills SET policy_area = ?4
+                 WHERE congress = ?1 AND bill_type = ?2 AND number = ?3",
+                params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    subjects.policy_area.name,
+                ],
+            )?;
+            let mut statement = tx.prepare_cached(
+                "INSERT OR IGNORE INTO subjects (congress, bill_type, number, name)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for subject in &subjects.legislative_subjects {
+                statement.execute(params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    subject.name,
+                ])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn replace_summaries(&mut self, bill: &BillId, summaries: &[Summary]) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "summaries", bill)?;
+            let mut statement = tx.prepare_cached(
+                "INSERT INTO summaries (congress, bill_type, number, version_code, action_date,
+                    action_desc, update_date, text)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT DO UPDATE SET
+                    action_desc = excluded.action_desc,
+                    update_date = excluded.update_date,
+                    text = excluded.text",
+            )?;
+            for summary in summaries {
+                statement.execute(params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    summary.version_code.code(),
+                    summary.action_date,
+                    summary.action_desc,
+                    timestamp(&summary.update_date),
+                    summary.text,
+                ])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn replace_titles(&mut self, bill: &BillId, titles: &[Title]) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "titles", bill)?;
+            let mut statement = tx.prepare_cached(
+                "INSERT INTO titles (congress, bill_type, number, title_type, title, chamber,
+                    bill_text_version_code)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT DO UPDATE SET
+                    chamber = excluded.chamber,
+                    bill_text_version_code = excluded.bill_text_version_code",
+            )?;
+            for title in titles {
+                statement.execute(params![
+                    bill.congress,
+                    name(&bill.bill_type),
+                    bill.number,
+                    title.title_type,
+                    title.title,
+                    name(&title.chamber_name),
+                    title.bill_text_version_code.code(),
+                ])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn replace_related_bills(&mut self, bill: &BillId, related: &[RelatedBill]) -> Result<()> {
+        self.transaction(|tx| {
+            delete_children(tx, "related_bills", bill)?;
+            let mut statement = tx.prepare_cached(
+                "INSERT OR IGNORE INTO related_bills (congress, bill_type, number,
+                    related_congress, related_bill_type, related_number,
+                    relationship, identified_by)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )?;
+            for related in related {
+                for detail in &related.relationship_details {
+                    statement.execute(params![
+                        bill.congress,
+                        name(&bill.bill_type),
+                        bill.number,
+                        related.congress,
+                        name(&related.bill_type),
+                        related.number,
+                        detail.type_,
+                        detail.identified_by,
+                    ])?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// The position an incremental sync named `name` last recorded
+    pub fn checkpoint(&self, name: &str) -> Result<Option<String>> {
+        self.connection
+            .query_row(
+                "SELECT position FROM checkpoints WHERE name = ?1",
+                [name],
+                |row| row.get(0),
+            )
+            .optional()
+            .context(StoreSnafu)
+    }
+
+    /// Records how far an incremental sync named `name` got, e.g. the latest update date seen
+    pub fn set_checkpoint(&self, name: &str, position: &str) -> Result<()> {
+        self.connection
+            .execute(
+                "INSERT INTO checkpoints (name, position, updated_at) VALUES (?1, ?2, ?3)
+                 ON CONFLICT DO UPDATE SET
+                    position = excluded.position,
+                    updated_at = excluded.updated_at",
+                params![name, position, timestamp(&Utc::now())],
+            )
+            .context(StoreSnafu)?;
+        Ok(())
+    }
+
+    /// When the checkpoint named `name` was last recorded
+    pub fn checkpoint_updated_at(&self, name: &str) -> Result<Option<DateTime<Utc>>> {
+        self.connection
+            .query_row(
+                "SELECT updated_at FROM checkpoints WHERE name = ?1",
+                [name],
+                |row| row.get(0),
+            )
+            .optional()
+            .context(StoreSnafu)
+    }
+}
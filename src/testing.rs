@@ -0,0 +1,280 @@
+//! Record and replay API responses for offline testing
+//!
+//! [`MockServer::record`] proxies requests to the real API and saves every
+//! response as a JSON fixture, forwarding the API key header and stripping the
+//! key from the body. [`MockServer::replay`] serves those fixtures back so a
+//! client built with [`MockServer::client_builder`] can run without a key or a
+//! network connection.
+//!
+//! ```no_run
+//! # async fn run() -> congress::Result<()> {
+//! use congress::{bill_type::BillType, testing::MockServer};
+//!
+//! let server = MockServer::replay("tests/fixtures").await?;
+//! let client = server.client_builder()?.build()?;
+//! let bill = client.bill(118, BillType::House, 3746).send().await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    api_key::{ApiKey, API_KEY_HEADER},
+    error::{FixtureSnafu, MockServerSnafu, SendSnafu},
+    ClientBuilder, Result,
+};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::sync::oneshot;
+use url::Url;
+
+/// A recorded API response
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Fixture {
+    pub path: String,
+    /// The query string without the API key, with parameters sorted
+    pub query: String,
+    pub status: u16,
+    /// The response body; non-JSON bodies are stored as a string
+    pub body: serde_json::Value,
+}
+
+impl Fixture {
+    /// The file name the fixture for a request is stored under
+    pub fn file_name(path: &str, query: &str) -> String {
+        let mut name: String = path
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c == '/' { '_' } else { c })
+            .collect();
+        if !query.is_empty() {
+            name.push_str("__");
+            name.extend(query.chars().map(|c| match c {
+                '=' => '-',
+                c if c.is_ascii_alphanumeric() || c == '-' || c == '.' => c,
+                _ => '_',
+            }));
+        }
+        name.push_str(".json");
+        name
+    }
+
+    pub fn load(path: &Path) -> Result<Fixture> {
+        let file = std::fs::read(path).context(FixtureSnafu { path })?;
+        serde_json::from_slice(&file)
+            .map_err(std::io::Error::from)
+            .context(FixtureSnafu { path })
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        let path = dir.join(Self::file_name(&self.path, &self.query));
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(std::io::Error::from)
+            .context(FixtureSnafu { path: &path })?;
+        std::fs::create_dir_all(dir).context(FixtureSnafu { path: dir })?;
+        std::fs::write(&path, json).context(FixtureSnafu { path: &path })?;
+        Ok(path)
+    }
+
+    fn response(&self) -> Response<Body> {
+        let body = match &self.body {
+            serde_json::Value::String(raw) => raw.clone(),
+            json => json.to_string(),
+        };
+        Response::builder()
+            .status(self.status)
+            .body(Body::from(body))
+            .unwrap_or_default()
+    }
+}
+
+/// Sorts the query parameters and drops the API key
+fn normalize_query(query: Option<&str>) -> String {
+    let mut pairs: Vec<(String, String)> =
+        url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+            .filter(|(k, _)| k != "api_key")
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+    pairs.sort();
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish()
+}
+
+enum Mode {
+    Replay,
+    Record {
+        upstream: Url,
+        client: reqwest::Client,
+    },
+}
+
+struct State {
+    fixtures: PathBuf,
+    mode: Mode,
+}
+
+impl State {
+    async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        let path = request.uri().path().to_string();
+        let query = normalize_query(request.uri().query());
+
+        let fixture = match &self.mode {
+            Mode::Replay => {
+                let file = self.fixtures.join(Fixture::file_name(&path, &query));
+                Fixture::load(&file)
+            }
+            Mode::Record { upstream, client } => {
+                let api_key = request
+                    .headers()
+                    .get(API_KEY_HEADER)
+                    .and_then(|k| k.to_str().ok());
+                self.record(
+                    upstream,
+                    client,
+                    &path,
+                    request.uri().query(),
+                    query,
+                    api_key,
+                )
+                .await
+            }
+        };
+
+        match fixture {
+            Ok(fixture) => fixture.response(),
+            Err(e) => {
+                let error = serde_json::json!({
+                    "error": { "code": "FIXTURE_ERROR", "message": e.to_string() }
+                });
+                Response::builder()
+                    .status(StatusCode::NOT_FOUND)
+                    .body(Body::from(error.to_string()))
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    async fn record(
+        &self,
+        upstream: &Url,
+        client: &reqwest::Client,
+        path: &str,
+        raw_query: Option<&str>,
+        query: String,
+        api_key: Option<&str>,
+    ) -> Result<Fixture> {
+        let mut url = upstream.join(path).context(crate::error::InvalidUrlSnafu)?;
+        url.set_query(raw_query);
+
+        let mut request = client.get(url);
+        if let Some(key) = api_key {
+            request = request.header(API_KEY_HEADER, key);
+        }
+        let response = request.send().await.context(SendSnafu)?;
+        let status = response.status().as_u16();
+        let mut text = response.text().await.context(crate::error::ResponseSnafu)?;
+        if let Some(key) = api_key.filter(|k| !k.is_empty()) {
+            text = text.replace(key, "REDACTED");
+        }
+        let body = serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text));
+
+        let fixture = Fixture {
+            path: path.to_string(),
+            query,
+            status,
+            body,
+        };
+        fixture.save(&self.fixtures)?;
+        Ok(fixture)
+    }
+}
+
+/// A local HTTP server replaying or recording fixtures. It shuts down when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Serves the fixtures in `fixtures`, answering unknown requests with a 404
+    pub async fn replay(fixtures: impl Into<PathBuf>) -> Result<MockServer> {
+        Self::start(State {
+            fixtures: fixtures.into(),
+            mode: Mode::Replay,
+        })
+    }
+
+    /// Forwards requests to `upstream` and saves every response into `fixtures`
+    pub async fn record(
+        fixtures: impl Into<PathBuf>,
+        upstream: impl Into<Url>,
+    ) -> Result<MockServer> {
+        Self::start(State {
+            fixtures: fixtures.into(),
+            mode: Mode::Record {
+                upstream: upstream.into(),
+                client: reqwest::Client::new(),
+            },
+        })
+    }
+
+    fn start(state: State) -> Result<MockServer> {
+        let state = Arc::new(state);
+        let make = make_service_fn(move |_| {
+            let state = state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let state = state.clone();
+                    async move { Ok::<_, Infallible>(state.handle(request).await) }
+                }))
+            }
+        });
+
+        let (shutdown, rx) = oneshot::channel::<()>();
+        let server = Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .context(MockServerSnafu)?
+            .serve(make);
+        let addr = server.local_addr();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            rx.await.ok();
+        }));
+
+        Ok(MockServer {
+            addr,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// The base URL to point a client at
+    pub fn url(&self) -> Url {
+        Url::parse(&format!("http://{}/", self.addr)).unwrap()
+    }
+
+    /// A client builder pointed at this server. When replaying the API key is
+    /// irrelevant; when recording, set one with [`MockServer::client_builder_with_key`].
+    pub fn client_builder(&self) -> Result<ClientBuilder> {
+        self.client_builder_with_key("")
+    }
+
+    pub fn client_builder_with_key(&self, api_key: impl Into<ApiKey>) -> Result<ClientBuilder> {
+        ClientBuilder::new(api_key).base_url(self.url())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    api_key::API_KEY_HEADER,
+    error::{ClientBuildSnafu, ResponseSnafu, SendSnafu},
+    Result,
+};
+use snafu::ResultExt;
+use std::fmt::Debug;
+use url::Url;
+
+/// Re-exported for implementing [`HttpTransport`]
+pub use async_trait::async_trait;
+
+/// A GET request for a transport to send
+///
+/// Its `Debug` output redacts the API key header.
+#[derive(Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The full URL, including the query string
+    pub url: Url,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    pub fn new(url: Url) -> Self {
+        HttpRequest {
+            url,
+            headers: vec![],
+        }
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Debug for HttpRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let headers: Vec<(&str, &str)> = self
+            .headers
+            .iter()
+            .map(
+                |(name, value)| match name.eq_ignore_ascii_case(API_KEY_HEADER) {
+                    true => (name.as_str(), "REDACTED"),
+                    false => (name.as_str(), value.as_str()),
+                },
+            )
+            .collect();
+        f.debug_struct("HttpRequest")
+            .field("url", &self.url.as_str())
+            .field("headers", &headers)
+            .finish()
+    }
+}
+
+/// The response a transport received
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// The value of the first header with the given name, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Sends HTTP requests on behalf of a [`Client`](crate::Client)
+///
+/// The default transport is [`ReqwestTransport`]. Other HTTP stacks, or test
+/// doubles, can be plugged in with [`ClientBuilder::transport`](crate::ClientBuilder::transport).
+/// Failures of the underlying stack can be reported with
+/// [`Error::TransportError`](crate::error::Error::TransportError).
+#[async_trait]
+pub trait HttpTransport: Debug + Send + Sync {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Result<Self> {
+        let client = reqwest::ClientBuilder::new()
+            .build()
+            .context(ClientBuildSnafu)?;
+        Ok(ReqwestTransport { client })
+    }
+
+    /// Wraps an already configured `reqwest` client
+    pub fn from_client(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut builder = self.client.get(request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.send().await.context(SendSnafu)?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(n, v)| Some((n.to_string(), v.to_str().ok()?.to_string())))
+            .collect();
+        let body = response.bytes().await.context(ResponseSnafu)?.to_vec();
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
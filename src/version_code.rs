@@ -0,0 +1,259 @@
+use crate::chamber::Chamber;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+macro_rules! text_version_codes {
+    ($($variant:ident, $code:literal, $name:literal, $chamber:expr;)+) => {
+        /// A GPO bill text version code
+        ///
+        /// Variants are declared, and therefore ordered, by legislative stage:
+        /// introduction, referral, reporting, passage in a chamber, amendment
+        /// between the chambers, enrollment and enactment. Versions this crate
+        /// doesn't know about are kept in [`TextVersionCode::Other`] and sort last.
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum TextVersionCode {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+            Other(String),
+        }
+
+        impl TextVersionCode {
+            /// All version codes in legislative stage order
+            pub const ALL: &'static [TextVersionCode] = &[$(Self::$variant),+];
+
+            /// The GPO code, e.g. `IH`, or the value as the API returned it if unknown
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)+
+                    Self::Other(code) => code,
+                }
+            }
+
+            /// The human readable name, e.g. `Introduced in House`, if the version is known
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($name),)+
+                    Self::Other(_) => None,
+                }
+            }
+
+            /// The chamber the version originates from, if it is specific to one
+            pub fn chamber(&self) -> Option<Chamber> {
+                match self {
+                    $(Self::$variant => $chamber,)+
+                    Self::Other(_) => None,
+                }
+            }
+        }
+    };
+}
+
+const HOUSE: Option<Chamber> = Some(Chamber::House);
+const SENATE: Option<Chamber> = Some(Chamber::Senate);
+
+text_version_codes! {
+    IntroducedInHouse, "IH", "Introduced in House", HOUSE;
+    IntroducedInSenate, "IS", "Introduced in Senate", SENATE;
+    AdditionalSponsorsHouse, "ASH", "Additional Sponsors House", HOUSE;
+    AdditionalSponsorsSenate, "SAS", "Additional Sponsors Senate", SENATE;
+    SponsorChange, "SC", "Sponsor Change", None;
+    ReferredInHouse, "RFH", "Referred in House", HOUSE;
+    ReferredInSenate, "RFS", "Referred in Senate", SENATE;
+    ReferredToCommitteeHouse, "RTH", "Referred to Committee House", HOUSE;
+    ReferredToCommitteeSenate, "RTS", "Referred to Committee Senate", SENATE;
+    ReferenceChangeHouse, "RCH", "Reference Change House", HOUSE;
+    ReferenceChangeSenate, "RCS", "Reference Change Senate", SENATE;
+    ReferralInstructionsHouse, "RIH", "Referral Instructions House", HOUSE;
+    ReferralInstructionsSenate, "RIS", "Referral Instructions Senate", SENATE;
+    CommitteeDischargedHouse, "CDH", "Committee Discharged House", HOUSE;
+    CommitteeDischargedSenate, "CDS", "Committee Discharged Senate", SENATE;
+    ReportedInHouse, "RH", "Reported in House", HOUSE;
+    ReportedInSenate, "RS", "Reported in Senate", SENATE;
+    ReferredWithAmendmentsHouse, "RAH", "Referred with Amendments House", HOUSE;
+    ReferredWithAmendmentsSenate, "RAS", "Referred with Amendments Senate", SENATE;
+    PlacedOnCalendarHouse, "PCH", "Placed on Calendar House", HOUSE;
+    PlacedOnCalendarSenate, "PCS", "Placed on Calendar Senate", SENATE;
+    HeldAtDeskHouse, "HDH", "Held at Desk House", HOUSE;
+    HeldAtDeskSenate, "HDS", "Held at Desk Senate", SENATE;
+    OrderedToBePrintedHouse, "OPH", "Ordered to be Printed House", HOUSE;
+    OrderedToBePrintedSenate, "OPS", "Ordered to be Printed Senate", SENATE;
+    AmendmentOrderedToBePrinted, "AS", "Amendment Ordered to be Printed", SENATE;
+    FailedAmendmentHouse, "FAH", "Failed Amendment House", HOUSE;
+    LaidOnTableInHouse, "LTH", "Laid on Table in House", HOUSE;
+    LaidOnTableInSenate, "LTS", "Laid on Table in Senate", SENATE;
+    IndefinitelyPostponedInHouse, "IPH", "Indefinitely Postponed in House", HOUSE;
+    IndefinitelyPostponedInSenate, "IPS", "Indefinitely Postponed in Senate", SENATE;
+    FailedPassageHouse, "FPH", "Failed Passage House", HOUSE;
+    FailedPassageSenate, "FPS", "Failed Passage Senate", SENATE;
+    PreviousActionVitiated, "PAV", "Previous Action Vitiated", None;
+    ConsideredAndPassedHouse, "CPH", "Considered and Passed House", HOUSE;
+    ConsideredAndPassedSenate, "CPS", "Considered and Passed Senate", SENATE;
+    AgreedToHouse, "ATH", "Agreed to House", HOUSE;
+    AgreedToSenate, "ATS", "Agreed to Senate", SENATE;
+    EngrossedInHouse, "EH", "Engrossed in House", HOUSE;
+    EngrossedInSenate, "ES", "Engrossed in Senate", SENATE;
+    EngrossedAndDeemedPassedByHouse, "EPH", "Engrossed and Deemed Passed by House", HOUSE;
+    PrintedAsPassed, "PAP", "Printed as Passed", None;
+    PublicPrint, "PP", "Public Print", None;
+    ReceivedInHouse, "RDH", "Received in House", HOUSE;
+    ReceivedInSenate, "RDS", "Received in Senate", SENATE;
+    EngrossedAmendmentHouse, "EAH", "Engrossed Amendment House", HOUSE;
+    EngrossedAmendmentSenate, "EAS", "Engrossed Amendment Senate", SENATE;
+    OrderedToBePrintedWithHouseAmendment, "PWAH", "Ordered to be Printed with House Amendment", SENATE;
+    EnrolledBill, "ENR", "Enrolled Bill", None;
+    ReEnrolledBill, "RENR", "Re-enrolled Bill", None;
+    PublicLaw, "PL", "Public Law", None;
+    PrivateLaw, "PRL", "Private Law", None;
+}
+
+impl std::fmt::Display for TextVersionCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for TextVersionCode {
+    type Err = String;
+
+    /// Parses either the code (`ENR`) or the name (`Enrolled Bill`), ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .iter()
+            .find(|v| {
+                v.code().eq_ignore_ascii_case(s)
+                    || v.name().is_some_and(|n| n.eq_ignore_ascii_case(s))
+            })
+            .cloned()
+            .ok_or_else(|| format!("unknown text version code: {s}"))
+    }
+}
+
+impl<'de> Deserialize<'de> for TextVersionCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(code.parse().unwrap_or(Self::Other(code)))
+    }
+}
+
+impl Serialize for TextVersionCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// Serializes a [`TextVersionCode`] by name, the way the text endpoint returns it
+pub(crate) fn serialize_name<S>(code: &TextVersionCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(code.name().unwrap_or(code.code()))
+}
+
+macro_rules! summary_version_codes {
+    ($($variant:ident, $code:literal, $name:literal, $chamber:expr;)+) => {
+        /// A CRS bill summary version code
+        ///
+        /// Summaries are keyed by two digit codes which are distinct from the
+        /// GPO text version codes. Codes this crate doesn't know about are kept
+        /// in [`SummaryVersionCode::Other`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum SummaryVersionCode {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+            Other(String),
+        }
+
+        impl SummaryVersionCode {
+            /// The two digit code, e.g. `00`
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)+
+                    Self::Other(code) => code,
+                }
+            }
+
+            /// The human readable name, if the code is known
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($name),)+
+                    Self::Other(_) => None,
+                }
+            }
+
+            /// The chamber the summarized action took place in, if it is specific to one
+            pub fn chamber(&self) -> Option<Chamber> {
+                match self {
+                    $(Self::$variant => $chamber,)+
+                    Self::Other(_) => None,
+                }
+            }
+        }
+
+        impl From<&str> for SummaryVersionCode {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => Self::$variant,)+
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+summary_version_codes! {
+    Introduced, "00", "Introduced", None;
+    ReportedToHouse, "07", "Reported to House", HOUSE;
+    ReportedToHouseAmended, "17", "Reported to House with amendment(s)", HOUSE;
+    ReportedToSenate, "25", "Reported to Senate", SENATE;
+    LaidOnTableInHouse, "33", "Laid on table in House", HOUSE;
+    IndefinitelyPostponedInSenate, "34", "Indefinitely postponed in Senate", SENATE;
+    PassedSenateAmended, "35", "Passed Senate amended", SENATE;
+    PassedHouseAmended, "36", "Passed House amended", HOUSE;
+    FailedOfPassageInSenate, "37", "Failed of passage in Senate", SENATE;
+    FailedOfPassageInHouse, "38", "Failed of passage in House", HOUSE;
+    PublicLaw, "49", "Public Law", None;
+    EnrolledBill, "52", "Passed Congress/Enrolled Bill", None;
+    PassedHouse, "53", "Passed House", HOUSE;
+    PassedSenate, "54", "Passed Senate", SENATE;
+    DischargedFromHouseCommittee, "77", "Discharged from House committee", HOUSE;
+    DischargedFromSenateCommittee, "78", "Discharged from Senate committee", SENATE;
+    ReportedToHouseWithoutAmendment, "79", "Reported to House without amendment", HOUSE;
+    ReportedToSenateWithoutAmendment, "80", "Reported to Senate without amendment", SENATE;
+    PassedHouseWithoutAmendment, "81", "Passed House without amendment", HOUSE;
+    PassedSenateWithoutAmendment, "82", "Passed Senate without amendment", SENATE;
+}
+
+impl std::fmt::Display for SummaryVersionCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for SummaryVersionCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+impl Serialize for SummaryVersionCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{
+    chamber::Chamber,
+    error::{MissingBioguideIdSnafu, XmlDeserializeSnafu},
+    party::Party,
+    state::State,
+    Client, Result,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use snafu::{ensure, ResultExt};
+use url::Url;
+
+/// A recorded vote referenced by a floor action
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedVote {
+    pub chamber: Chamber,
+    pub congress: u32,
+    pub date: DateTime<Utc>,
+    pub roll_number: u32,
+    pub session_number: u32,
+    /// The House Clerk or Senate roll call XML
+    pub url: Url,
+}
+
+impl RecordedVote {
+    /// Downloads and parses the roll call from the House Clerk or the Senate
+    pub async fn fetch(&self, client: &Client) -> Result<RollCall> {
+        let xml = client.get_document(self.url.clone()).await?;
+        RollCall::parse(self.chamber, &xml)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub enum VotePosition {
+    /// Yea or Aye
+    Yea,
+    /// Nay or No
+    Nay,
+    Present,
+    NotVoting,
+    /// Positions on questions that aren't yes or no, e.g. `Guilty` or a
+    /// candidate's name in the election of the Speaker
+    Other(String),
+}
+
+impl From<&str> for VotePosition {
+    fn from(position: &str) -> Self {
+        match position.trim() {
+            "Yea" | "Aye" => Self::Yea,
+            "Nay" | "No" => Self::Nay,
+            "Present" => Self::Present,
+            "Not Voting" => Self::NotVoting,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberVote {
+    /// Set for House votes, which identify members by bioguide ID, and for
+    /// Senate votes once [`RollCall::set_bioguide_ids`] has filled it in
+    pub bioguide_id: Option<String>,
+    /// Set for Senate votes, which identify members by LIS ID
+    pub lis_member_id: Option<String>,
+    pub name: String,
+    pub party: Party,
+    pub state: State,
+    pub position: VotePosition,
+}
+
+/// A roll call vote with the position of every member
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RollCall {
+    pub chamber: Chamber,
+    pub congress: u32,
+    pub session: u32,
+    pub roll_number: u32,
+    pub date: Option<NaiveDate>,
+    pub question: String,
+    pub result: String,
+    pub positions: Vec<MemberVote>,
+}
+
+impl RollCall {
+    /// Parses a House Clerk or Senate roll call XML document
+    pub fn parse(chamber: Chamber, xml: &[u8]) -> Result<RollCall> {
+        match chamber {
+            Chamber::House => Self::parse_house(xml),
+            Chamber::Senate => Self::parse_senate(xml),
+        }
+    }
+
+    /// Parses a House Clerk `rollcall-vote` document
+    pub fn parse_house(xml: &[u8]) -> Result<RollCall> {
+        let vote: HouseRollCall = quick_xml::de::from_reader(xml).context(XmlDeserializeSnafu)?;
+        let metadata = vote.metadata;
+
+        Ok(RollCall {
+            chamber: Chamber::House,
+            congress: metadata.congress,
+            session: leading_number(&metadata.session),
+            roll_number: metadata.rollcall_num,
+            date: NaiveDate::parse_from_str(&metadata.action_date, "%d-%b-%Y").ok(),
+            question: metadata.vote_question.unwrap_or_default(),
+            result: metadata.vote_result.unwrap_or_default(),
+            positions: vote
+                .data
+                .recorded_votes
+                .into_iter()
+                .map(|v| MemberVote {
+                    bioguide_id: Some(v.legislator.name_id),
+                    lis_member_id: None,
+                    name: v.legislator.name,
+                    party: v.legislator.party,
+                    state: v.legislator.state,
+                    position: VotePosition::from(v.vote.as_str()),
+                })
+                .collect(),
+        })
+    }
+
+    /// Parses a Senate `roll_call_vote` document
+    pub fn parse_senate(xml: &[u8]) -> Result<RollCall> {
+        let vote: SenateRollCall = quick_xml::de::from_reader(xml).context(XmlDeserializeSnafu)?;
+
+        Ok(RollCall {
+            chamber: Chamber::Senate,
+            congress: vote.congress,
+            session: vote.session,
+            roll_number: leading_number(&vote.vote_number),
+            date: NaiveDateTime::parse_from_str(&vote.vote_date, "%B %d, %Y, %I:%M %p")
+                .ok()
+                .map(|d| d.date()),
+            question: vote
+                .question
+                .or(vote.vote_question_text)
+                .unwrap_or_default(),
+            result: vote.vote_result.unwrap_or_default(),
+            positions: vote
+                .members
+                .members
+                .into_iter()
+                .map(|m| MemberVote {
+                    bioguide_id: None,
+                    lis_member_id: Some(m.lis_member_id),
+                    name: format!("{} {}", m.first_name, m.last_name),
+                    party: m.party,
+                    state: m.state,
+                    position: VotePosition::from(m.vote_cast.as_str()),
+                })
+                .collect(),
+        })
+    }
+
+    /// The position of the member with the given bioguide ID, or `None` if
+    /// they aren't in the roll call
+    ///
+    /// Senate roll calls only carry LIS IDs, so a member who isn't found fails
+    /// with [`Error::MissingBioguideId`](crate::error::Error::MissingBioguideId)
+    /// while any member is still unmapped, since they may be that member. Fill
+    /// the IDs in with [`RollCall::set_bioguide_ids`], or use
+    /// [`RollCall::position_by_name`] when no LIS to bioguide mapping is at hand.
+    pub fn position_of(&self, bioguide_id: &str) -> Result<Option<&VotePosition>> {
+        if let Some(member) = self
+            .positions
+            .iter()
+            .find(|m| m.bioguide_id.as_deref() == Some(bioguide_id))
+        {
+            return Ok(Some(&member.position));
+        }
+        ensure!(
+            self.positions.iter().all(|m| m.bioguide_id.is_some()),
+            MissingBioguideIdSnafu {
+                chamber: self.chamber,
+                roll_number: self.roll_number,
+            }
+        );
+        Ok(None)
+    }
+
+    /// Fills in the bioguide IDs of members known only by LIS ID, such as every
+    /// member of a Senate roll call. `lookup` maps an LIS ID like `S354` to a
+    /// bioguide ID, e.g. from the `congress-legislators` dataset. Returns how
+    /// many members are still without a bioguide ID.
+    pub fn set_bioguide_ids<F>(&mut self, mut lookup: F) -> usize
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut missing = 0;
+        for member in &mut self.positions {
+            if member.bioguide_id.is_none() {
+                member.bioguide_id = member.lis_member_id.as_deref().and_then(&mut lookup);
+            }
+            if member.bioguide_id.is_none() {
+                missing += 1;
+            }
+        }
+        missing
+    }
+
+    /// The position of the member with the given last name from the given state
+    pub fn position_by_name(&self, last_name: &str, state: State) -> Option<&VotePosition> {
+        self.positions
+            .iter()
+            .find(|m| {
+                m.state == state
+                    && m.name
+                        .rsplit(' ')
+                        .next()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(last_name))
+            })
+            .map(|m| &m.position)
+    }
+
+    /// Number of members that took the given position
+    pub fn count(&self, position: &VotePosition) -> usize {
+        self.positions
+            .iter()
+            .filter(|m| &m.position == position)
+            .count()
+    }
+}
+
+/// Parses the number at the start of values like `1st` or `00140`
+fn leading_number(s: &str) -> u32 {
+    s.chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .unwrap_or_default()
+}
+
+#[derive(Deserialize)]
+struct HouseRollCall {
+    #[serde(rename = "vote-metadata")]
+    metadata: HouseMetadata,
+    #[serde(rename = "vote-data")]
+    data: HouseVoteData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct HouseMetadata {
+    congress: u32,
+    session: String,
+    rollcall_num: u32,
+    vote_question: Option<String>,
+    vote_result: Option<String>,
+    action_date: String,
+}
+
+#[derive(Deserialize)]
+struct HouseVoteData {
+    #[serde(rename = "recorded-vote", default)]
+    recorded_votes: Vec<HouseRecordedVote>,
+}
+
+#[derive(Deserialize)]
+struct HouseRecordedVote {
+    legislator: HouseLegislator,
+    vote: String,
+}
+
+#[derive(Deserialize)]
+struct HouseLegislator {
+    #[serde(rename = "@name-id")]
+    name_id: String,
+    #[serde(rename = "@party")]
+    party: Party,
+    #[serde(rename = "@state")]
+    state: State,
+    #[serde(rename = "$text")]
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SenateRollCall {
+    congress: u32,
+    session: u32,
+    vote_number: String,
+    vote_date: String,
+    question: Option<String>,
+    vote_question_text: Option<String>,
+    vote_result: Option<String>,
+    members: SenateMembers,
+}
+
+#[derive(Deserialize)]
+struct SenateMembers {
+    #[serde(rename = "member", default)]
+    members: Vec<SenateMember>,
+}
+
+#[derive(Deserialize)]
+struct SenateMember {
+    last_name: String,
+    first_name: String,
+    party: Party,
+    state: State,
+    vote_cast: String,
+    lis_member_id: String,
+}
@@ -0,0 +1,299 @@
+//! Poll a set of bills and report what changed
+//!
+//! A [`Watcher`] keeps a snapshot of each bill. On every poll it fetches the
+//! bill, and only when `updateDate` or `updateDateIncludingText` moved does it
+//! refetch the actions, cosponsors or text versions whose counts changed. The
+//! first poll of a bill records its snapshot without reporting anything.
+//!
+//! ```no_run
+//! # async fn run(client: congress::Client) -> congress::Result<()> {
+//! use congress::{bill_id::BillId, bill_type::BillType, watch::Watcher};
+//! use std::time::Duration;
+//!
+//! let (events, mut received) = tokio::sync::mpsc::channel(64);
+//! let watcher = Watcher::new(&client, [BillId::new(118, BillType::House, 3746)]);
+//! let watching = watcher.run(Duration::from_secs(15 * 60), events);
+//! let printing = async {
+//!     while let Some(event) = received.recv().await {
+//!         println!("{event:?}");
+//!     }
+//! };
+//! let (result, _) = tokio::join!(watching, printing);
+//! result
+//! # }
+//! ```
+use crate::{
+    bill::{actions::Action, cosponsors::Cosponsor, text::TextVersion, Bill, Law},
+    bill_id::BillId,
+    pagination::PagedResponse,
+    parameters::PageParameters,
+    Client, Result,
+};
+use chrono::NaiveDate;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::mpsc, time::MissedTickBehavior};
+
+/// Something that happened to a watched bill
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    NewAction {
+        bill: BillId,
+        action: Action,
+    },
+    CosponsorAdded {
+        bill: BillId,
+        cosponsor: Cosponsor,
+    },
+    /// The cosponsor with their withdrawal date, or as they were last listed
+    /// if the API dropped them instead
+    CosponsorWithdrawn {
+        bill: BillId,
+        cosponsor: Cosponsor,
+    },
+    NewTextVersion {
+        bill: BillId,
+        version: TextVersion,
+    },
+    /// Dated by the bill's latest action when the law appeared
+    BecameLaw {
+        bill: BillId,
+        law: Law,
+        date: NaiveDate,
+    },
+}
+
+impl Event {
+    /// The bill the event happened to
+    pub fn bill(&self) -> BillId {
+        match self {
+            Event::NewAction { bill, .. }
+            | Event::CosponsorAdded { bill, .. }
+            | Event::CosponsorWithdrawn { bill, .. }
+            | Event::NewTextVersion { bill, .. }
+            | Event::BecameLaw { bill, .. } => *bill,
+        }
+    }
+}
+
+/// What a bill looked like when it was last polled
+#[derive(Debug, Clone)]
+struct Snapshot {
+    bill: Bill,
+    actions: Vec<Action>,
+    cosponsors: Vec<Cosponsor>,
+    text_versions: Vec<TextVersion>,
+}
+
+/// Polls a set of bills, diffing each against its previous snapshot
+#[derive(Debug)]
+pub struct Watcher<'client> {
+    client: &'client Client,
+    bills: Vec<BillId>,
+    snapshots: HashMap<BillId, Snapshot>,
+}
+
+impl<'client> Watcher<'client> {
+    pub fn new(client: &'client Client, bills: impl IntoIterator<Item = BillId>) -> Self {
+        Watcher {
+            client,
+            bills: bills.into_iter().collect(),
+            snapshots: HashMap::new(),
+        }
+    }
+
+    /// Starts watching another bill from the next poll
+    pub fn watch(&mut self, bill: BillId) {
+        if !self.bills.contains(&bill) {
+            self.bills.push(bill);
+        }
+    }
+
+    /// Stops watching a bill and forgets its snapshot
+    pub fn unwatch(&mut self, bill: BillId) {
+        self.bills.retain(|b| *b != bill);
+        self.snapshots.remove(&bill);
+    }
+
+    pub fn bills(&self) -> &[BillId] {
+        &self.bills
+    }
+
+    /// Fetches one bill and returns what changed since it was last polled. The
+    /// snapshot is only replaced once every request for the bill succeeded.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "watch", skip_all, fields(bill = %id))
+    )]
+    pub async fn poll_bill(&mut self, id: BillId) -> Result<Vec<Event>> {
+        let handler = self.client.bill(id.congress, id.bill_type, id.number);
+        let bill = handler.send().await?.bill;
+
+        let Some(previous) = self.snapshots.get(&id) else {
+            let snapshot = Snapshot {
+                actions: all_pages(self.client, handler.actions().limit(250).send().await?).await?,
+                cosponsors: all_pages(self.client, handler.cosponsors().limit(250).send().await?)
+                    .await?,
+                text_versions: all_pages(self.client, handler.text().limit(250).send().await?)
+                    .await?,
+                bill,
+            };
+            self.snapshots.insert(id, snapshot);
+            return Ok(vec![]);
+        };
+
+        if bill.update_date == previous.bill.update_date
+            && bill.update_date_including_text == previous.bill.update_date_including_text
+        {
+            return Ok(vec![]);
+        }
+
+        let mut events = vec![];
+        let mut snapshot = previous.clone();
+
+        if bill.actions.count != previous.bill.actions.count {
+            let actions =
+                all_pages(self.client, handler.actions().limit(250).send().await?).await?;
+            events.extend(
+                actions
+                    .iter()
+                    .filter(|a| !previous.actions.iter().any(|p| same_action(p, a)))
+                    .map(|action| Event::NewAction {
+                        bill: id,
+                        action: action.clone(),
+                    }),
+            );
+            snapshot.actions = actions;
+        }
+
+        if cosponsor_counts(&bill) != cosponsor_counts(&previous.bill) {
+            let cosponsors =
+                all_pages(self.client, handler.cosponsors().limit(250).send().await?).await?;
+            let active = |list: &[Cosponsor], bioguide_id: &str| {
+                list.iter()
+                    .any(|c| c.bioguide_id == bioguide_id && !c.is_withdrawn())
+            };
+            events.extend(
+                cosponsors
+                    .iter()
+                    .filter(|c| !c.is_withdrawn() && !active(&previous.cosponsors, &c.bioguide_id))
+                    .map(|cosponsor| Event::CosponsorAdded {
+                        bill: id,
+                        cosponsor: cosponsor.clone(),
+                    }),
+            );
+            events.extend(
+                previous
+                    .cosponsors
+                    .iter()
+                    .filter(|c| !c.is_withdrawn() && !active(&cosponsors, &c.bioguide_id))
+                    .map(|old| Event::CosponsorWithdrawn {
+                        bill: id,
+                        cosponsor: cosponsors
+                            .iter()
+                            .find(|c| c.bioguide_id == old.bioguide_id)
+                            .unwrap_or(old)
+                            .clone(),
+                    }),
+            );
+            snapshot.cosponsors = cosponsors;
+        }
+
+        if bill.update_date_including_text != previous.bill.update_date_including_text
+            || text_count(&bill) != text_count(&previous.bill)
+        {
+            let versions = all_pages(self.client, handler.text().limit(250).send().await?).await?;
+            events.extend(
+                versions
+                    .iter()
+                    .filter(|v| {
+                        !previous
+                            .text_versions
+                            .iter()
+                            .any(|p| p.version_code == v.version_code && p.date == v.date)
+                    })
+                    .map(|version| Event::NewTextVersion {
+                        bill: id,
+                        version: version.clone(),
+                    }),
+            );
+            snapshot.text_versions = versions;
+        }
+
+        let old_laws = previous.bill.laws.as_deref().unwrap_or_default();
+        events.extend(
+            bill.laws
+                .iter()
+                .flatten()
+                .filter(|law| !old_laws.iter().any(|l| l.number == law.number))
+                .map(|law| Event::BecameLaw {
+                    bill: id,
+                    law: law.clone(),
+                    date: bill.latest_action.action_date,
+                }),
+        );
+
+        snapshot.bill = bill;
+        self.snapshots.insert(id, snapshot);
+        Ok(events)
+    }
+
+    /// Polls every bill each `interval`, sending the events to `events`, until
+    /// the receiver is dropped. A bill whose request fails with a retryable
+    /// error is skipped until the next round; any other error stops the watcher.
+    pub async fn run(mut self, interval: Duration, events: mpsc::Sender<Event>) -> Result<()> {
+        let mut ticks = tokio::time::interval(interval);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticks.tick().await;
+            for id in self.bills.clone() {
+                let changes = match self.poll_bill(id).await {
+                    Ok(changes) => changes,
+                    Err(e) if e.is_retryable() => continue,
+                    Err(e) => return Err(e),
+                };
+                for event in changes {
+                    if events.send(event).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+            if events.is_closed() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Actions have no ID, so they are matched on what the API shows of them
+fn same_action(a: &Action, b: &Action) -> bool {
+    a.action_date == b.action_date
+        && a.action_code == b.action_code
+        && a.text == b.text
+        && a.source_system.name == b.source_system.name
+}
+
+fn cosponsor_counts(bill: &Bill) -> Option<(u32, Option<u32>)> {
+    bill.cosponsors
+        .as_ref()
+        .map(|c| (c.count, c.count_including_withdrawn_cosponsors))
+}
+
+fn text_count(bill: &Bill) -> Option<u32> {
+    bill.text_versions.as_ref().map(|t| t.count)
+}
+
+async fn all_pages<P, T>(client: &Client, first: P) -> Result<Vec<T>>
+where
+    P: DeserializeOwned + PagedResponse<T>,
+    T: Clone,
+{
+    let mut items = first.get_items().clone();
+    let mut page = client.next(&first).await?;
+    while let Some(current) = page {
+        items.extend(current.get_items().iter().cloned());
+        page = client.next(&current).await?;
+    }
+    Ok(items)
+}
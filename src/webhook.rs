@@ -0,0 +1,202 @@
+//! Deliver [`watch`](crate::watch) events as signed webhook payloads
+//!
+//! Each delivery is a JSON `POST` of `{"sent_at": ..., "events": [...]}`. The
+//! body is signed with HMAC-SHA256 and the signature is sent in the
+//! [`SIGNATURE_HEADER`] header as `sha256=<hex>`, which receivers check with
+//! [`verify`].
+//!
+//! [`Webhook::forward`] retries a delivery that fails with a 5xx, a 429 or a
+//! connection error, waiting twice as long before each attempt. A delivery
+//! that still fails is handed to the caller as a [`FailedDelivery`] and
+//! forwarding carries on, so one bad response from the receiver doesn't stop
+//! the watcher feeding it.
+use crate::{
+    error::{Error, SendSnafu, UnexpectedStatusSnafu, WriteSnafu},
+    watch::Event,
+    Result,
+};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use snafu::{ensure, ResultExt};
+use std::{fmt::Write, time::Duration};
+use tokio::sync::mpsc;
+use url::Url;
+
+/// The header carrying the payload's signature
+pub const SIGNATURE_HEADER: &str = "X-Congress-Signature-256";
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Serialize)]
+struct Body<'a> {
+    sent_at: DateTime<Utc>,
+    events: &'a [Event],
+}
+
+/// A payload ready to send
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedPayload {
+    pub body: Vec<u8>,
+    /// `sha256=` followed by the hex encoded HMAC of the body
+    pub signature: String,
+}
+
+/// Signs `body` with `secret`, returning the value of the [`SIGNATURE_HEADER`] header
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(body);
+    let mut signature = String::from("sha256=");
+    for byte in mac.finalize().into_bytes() {
+        write!(signature, "{byte:02x}").unwrap();
+    }
+    signature
+}
+
+/// Checks a [`SIGNATURE_HEADER`] value against `body` in constant time
+pub fn verify(secret: &[u8], body: &[u8], signature: &str) -> bool {
+    let Some(hex) = signature.strip_prefix("sha256=") else {
+        return false;
+    };
+    let Some(bytes) = decode_hex(hex) else {
+        return false;
+    };
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(body);
+    mac.verify_slice(&bytes).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Events [`Webhook::forward`] gave up on after its retries
+#[derive(Debug)]
+pub struct FailedDelivery {
+    pub events: Vec<Event>,
+    /// The error from the last attempt
+    pub error: Error,
+}
+
+/// An endpoint to deliver events to
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    url: Url,
+    secret: Vec<u8>,
+    http: reqwest::Client,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl Webhook {
+    pub fn new(url: Url, secret: impl Into<Vec<u8>>) -> Self {
+        Webhook {
+            url,
+            secret: secret.into(),
+            http: reqwest::Client::new(),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// How often [`Webhook::forward`] retries a failed delivery, and how long
+    /// it waits before the first retry. Defaults to 3 retries after 1 second.
+    pub fn retry(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Serializes and signs `events`
+    pub fn payload(&self, events: &[Event]) -> Result<SignedPayload> {
+        let body = Body {
+            sent_at: Utc::now(),
+            events,
+        };
+        let body = serde_json::to_vec(&body)
+            .map_err(std::io::Error::from)
+            .context(WriteSnafu)?;
+        Ok(SignedPayload {
+            signature: sign(&self.secret, &body),
+            body,
+        })
+    }
+
+    /// Posts `events` in one payload. Any status other than 2xx is an error.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "webhook", skip_all, fields(events = events.len()))
+    )]
+    pub async fn deliver(&self, events: &[Event]) -> Result<()> {
+        self.post(&self.payload(events)?).await
+    }
+
+    async fn post(&self, payload: &SignedPayload) -> Result<()> {
+        let response = self
+            .http
+            .post(self.url.clone())
+            .header("Content-Type", "application/json")
+            .header(SIGNATURE_HEADER, &payload.signature)
+            .body(payload.body.clone())
+            .send()
+            .await
+            .context(SendSnafu)?;
+        let status = response.status().as_u16();
+        ensure!(
+            response.status().is_success(),
+            UnexpectedStatusSnafu {
+                status,
+                url: self.url.clone(),
+            }
+        );
+        Ok(())
+    }
+
+    /// Delivers events from a [`Watcher`](crate::watch::Watcher) until its
+    /// sender is dropped. Events that arrive together go in one payload. A
+    /// payload that can't be delivered is passed to `on_failure`, e.g. to
+    /// queue its events for later, and forwarding moves on to the next one.
+    pub async fn forward<F>(&self, mut events: mpsc::Receiver<Event>, mut on_failure: F)
+    where
+        F: FnMut(FailedDelivery),
+    {
+        while let Some(event) = events.recv().await {
+            let mut batch = vec![event];
+            while let Ok(event) = events.try_recv() {
+                batch.push(event);
+            }
+            if let Err(error) = self.deliver_with_retries(&batch).await {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(%error, events = batch.len(), "webhook delivery failed");
+                on_failure(FailedDelivery {
+                    events: batch,
+                    error,
+                });
+            }
+        }
+    }
+
+    /// Retries send the same signed payload, so receivers can drop duplicates
+    async fn deliver_with_retries(&self, events: &[Event]) -> Result<()> {
+        let payload = self.payload(events)?;
+        let mut backoff = self.backoff;
+        let mut retries = 0;
+        loop {
+            match self.post(&payload).await {
+                Err(e) if e.is_retryable() && retries < self.retries => {
+                    retries += 1;
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use congress::{
+    action_code::{ActionCategory, ActionCodeInfo},
+    bill::actions::{Action, SourceSystemCode},
+    chamber::Chamber,
+};
+use serde_json::json;
+
+#[test]
+fn code_info() {
+    let action: Action = serde_json::from_value(json!({
+        "actionCode": "H11100",
+        "actionDate": "2023-05-29",
+        "sourceSystem": { "code": 2, "name": "House floor actions" },
+        "text": "Referred to the House Committee on the Budget.",
+        "type": "IntroReferral",
+    }))
+    .unwrap();
+
+    let info = action.code_info().unwrap();
+    assert_eq!(info.category, ActionCategory::Referral);
+    assert_eq!(info.chamber, Some(Chamber::House));
+    assert_eq!(
+        action.source_system.code,
+        Some(SourceSystemCode::HouseFloorActions)
+    );
+    assert_eq!(
+        action.source_system.code.and_then(|c| c.chamber()),
+        Some(Chamber::House)
+    );
+
+    let code: SourceSystemCode = serde_json::from_value(json!(5)).unwrap();
+    assert_eq!(code, SourceSystemCode::Other(5));
+    assert_eq!(code.chamber(), None);
+    assert_eq!(serde_json::to_value(code).unwrap(), json!(5));
+
+    assert_eq!(
+        ActionCodeInfo::lookup("E40000").map(|i| i.description),
+        Some("Became Public Law")
+    );
+    assert!(ActionCodeInfo::lookup("X99999").is_none());
+}
@@ -0,0 +1,61 @@
+use congress::bill::{actions::ActionsResponse, committees::Committee};
+use serde_json::json;
+
+#[test]
+fn committee_and_calendar_actions() {
+    let response: ActionsResponse = serde_json::from_value(json!({
+        "actions": [
+            {
+                "actionCode": "H12410",
+                "actionDate": "2023-03-24",
+                "calendarNumber": { "calendar": "U00012" },
+                "sourceSystem": { "code": 2, "name": "House floor actions" },
+                "text": "Placed on the Union Calendar, Calendar No. 12.",
+                "type": "Calendars",
+            },
+            {
+                "actionDate": "2023-03-09",
+                "committees": [{
+                    "name": "Energy and Commerce Committee",
+                    "systemCode": "hsif00",
+                    "url": "https://api.congress.gov/v3/committee/house/hsif00",
+                }],
+                "sourceSystem": { "code": 1, "name": "House committee actions" },
+                "text": "Ordered to be Reported (Amended) by the Yeas and Nays: 27 - 22.",
+                "type": "Committee",
+            },
+            {
+                "actionDate": "2023-03-08",
+                "sourceSystem": { "code": 2, "name": "House floor actions" },
+                "text": "Considered under the provisions of rule H. Res. 260.",
+                "type": "Floor",
+            },
+        ],
+        "pagination": { "count": 3 },
+    }))
+    .unwrap();
+
+    assert_eq!(response.committee_actions().count(), 1);
+    assert_eq!(response.floor_actions().count(), 1);
+    assert_eq!(response.by_committee("hsif00").count(), 1);
+
+    let committee: Committee = serde_json::from_value(json!({
+        "activities": [],
+        "chamber": "House",
+        "name": "Energy and Commerce Committee",
+        "systemCode": "hsif00",
+        "type": "Standing",
+        "url": "https://api.congress.gov/v3/committee/house/hsif00",
+    }))
+    .unwrap();
+    let referral = response.committee_actions().next().unwrap();
+    assert_eq!(referral.committees[0], committee.committee);
+    assert_eq!(committee.committee.system_code, "hsif00");
+
+    let calendar = response.calendar_actions().next().unwrap();
+    assert_eq!(
+        calendar.calendar_number.as_ref().unwrap().number(),
+        Some(12)
+    );
+    assert!(calendar.committees.is_empty());
+}
@@ -0,0 +1,99 @@
+use congress::{
+    api_key::ApiKey,
+    bill_type::BillType,
+    error::Error,
+    transport::{async_trait, HttpRequest, HttpResponse, HttpTransport},
+    ClientBuilder, Result,
+};
+use std::sync::{Arc, Mutex};
+
+/// Answers with a 429 for every key in `exhausted`
+#[derive(Debug, Default, Clone)]
+struct QuotaTransport {
+    exhausted: Vec<&'static str>,
+    keys: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl HttpTransport for QuotaTransport {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let (_, key) = request
+            .headers
+            .iter()
+            .find(|(name, _)| name == "X-Api-Key")
+            .unwrap();
+        self.keys.lock().unwrap().push(key.clone());
+
+        let bill: serde_json::Value = super::fixture("v3_bill_118_hr_3746__format-json.json");
+        let (status, body) = match self.exhausted.contains(&key.as_str()) {
+            true => (
+                429,
+                r#"{"error":{"code":"OVER_RATE_LIMIT","message":"You have exceeded your rate limit."}}"#
+                    .to_string(),
+            ),
+            false => (200, bill.to_string()),
+        };
+        Ok(HttpResponse {
+            status,
+            headers: vec![],
+            body: body.into_bytes(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn rotate_on_quota() -> Result<()> {
+    let transport = QuotaTransport {
+        exhausted: vec!["FIRST"],
+        ..Default::default()
+    };
+    let client = ClientBuilder::new("FIRST")
+        .fallback_api_key("SECOND")
+        .transport(transport.clone())
+        .build()?;
+
+    let handler = client.bill(118, BillType::House, 3746);
+    handler.send().await?;
+    handler.send().await?;
+    assert_eq!(
+        *transport.keys.lock().unwrap(),
+        ["FIRST", "SECOND", "SECOND"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn all_keys_exhausted() -> Result<()> {
+    let transport = QuotaTransport {
+        exhausted: vec!["FIRST", "SECOND"],
+        ..Default::default()
+    };
+    let client = ClientBuilder::new("FIRST")
+        .fallback_api_key("SECOND")
+        .transport(transport.clone())
+        .build()?;
+
+    let error = client
+        .bill(118, BillType::House, 3746)
+        .send()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::RateLimited { .. }));
+    assert_eq!(*transport.keys.lock().unwrap(), ["FIRST", "SECOND"]);
+
+    Ok(())
+}
+
+#[test]
+fn redacted() -> Result<()> {
+    let client = ClientBuilder::new("SECRET").build()?;
+    assert!(!format!("{client:?}").contains("SECRET"));
+    assert_eq!(format!("{:?}", ApiKey::new("SECRET")), "ApiKey(REDACTED)");
+
+    let request = HttpRequest::new("https://api.congress.gov/".parse().unwrap())
+        .header("X-Api-Key", "SECRET");
+    assert!(!format!("{request:?}").contains("SECRET"));
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use congress::bill::{diff::Change, Bill, BillResponse};
+use serde_json::{json, Value};
+
+fn fixture() -> Value {
+    super::fixture::<Value>("v3_bill_118_hr_3746__format-json.json")["bill"].clone()
+}
+
+fn bill(value: Value) -> Bill {
+    serde_json::from_value::<BillResponse>(json!({ "bill": value }))
+        .unwrap()
+        .bill
+}
+
+#[test]
+fn unchanged() {
+    let bill = bill(fixture());
+    let diff = bill.diff(&bill.clone());
+    assert!(diff.is_empty());
+    assert!(diff.changed_fields().is_empty());
+    assert_eq!(serde_json::to_value(&diff).unwrap(), json!({}));
+}
+
+#[test]
+fn changes() {
+    let new = fixture();
+    let mut old = new.clone();
+    old["title"] = json!("Limit, Save, Grow Act");
+    old["latestAction"] =
+        json!({ "actionDate": "2023-05-31", "text": "Passed/agreed to in House" });
+    old["cosponsors"]["count"] = json!(0);
+    old.as_object_mut().unwrap().remove("policyArea");
+    old.as_object_mut().unwrap().remove("laws");
+    old["committeeReports"] = json!([]);
+
+    let diff = bill(old).diff(&bill(new));
+    assert_eq!(
+        diff.title,
+        Some(Change {
+            old: "Limit, Save, Grow Act".to_string(),
+            new: "Fiscal Responsibility Act of 2023".to_string(),
+        })
+    );
+    assert_eq!(
+        diff.latest_action.unwrap().new.text,
+        "Became Public Law No: 118-5."
+    );
+    assert_eq!(
+        diff.policy_area,
+        Some(Change {
+            old: None,
+            new: Some("Economics and Public Finance".to_string()),
+        })
+    );
+    assert_eq!(diff.cosponsor_count, Some(Change { old: 0, new: 1 }));
+    assert_eq!(diff.new_laws.len(), 1);
+    assert_eq!(diff.new_laws[0].number, "118-5");
+    assert!(diff.new_cbo_cost_estimates.is_empty());
+    assert!(diff.new_committee_reports.is_empty());
+}
+
+#[test]
+fn accumulated_lists() {
+    let old = fixture();
+    let mut new = old.clone();
+    new["cboCostEstimates"].as_array_mut().unwrap().push(json!({
+        "description": "As passed by the House",
+        "pubDate": "2023-06-01T12:00:00Z",
+        "title": "CBO's Estimate of the Budgetary Effects of H.R. 3746, as passed",
+        "url": "https://www.cbo.gov/publication/59230"
+    }));
+    new["committeeReports"] = json!([{
+        "citation": "H. Rept. 118-100",
+        "url": "https://api.congress.gov/v3/committee-report/118/HRPT/100?format=json"
+    }]);
+
+    let diff = bill(old).diff(&bill(new));
+    assert_eq!(diff.new_cbo_cost_estimates.len(), 1);
+    assert_eq!(
+        diff.new_cbo_cost_estimates[0].url.as_str(),
+        "https://www.cbo.gov/publication/59230"
+    );
+    assert_eq!(
+        diff.new_cbo_cost_estimates[0]
+            .pub_date
+            .unwrap()
+            .to_rfc3339(),
+        "2023-06-01T12:00:00+00:00"
+    );
+    assert_eq!(diff.new_committee_reports[0].citation, "H. Rept. 118-100");
+    assert_eq!(
+        diff.changed_fields(),
+        vec!["newCboCostEstimates", "newCommitteeReports"]
+    );
+
+    let json = serde_json::to_value(&diff).unwrap();
+    assert_eq!(
+        json.as_object().unwrap().keys().collect::<Vec<_>>(),
+        vec!["newCboCostEstimates", "newCommitteeReports"]
+    );
+    assert_eq!(
+        serde_json::from_value::<congress::bill::diff::BillDiff>(json).unwrap(),
+        diff
+    );
+}
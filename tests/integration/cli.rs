@@ -0,0 +1,127 @@
+use congress::{testing::MockServer, Result};
+use std::path::PathBuf;
+use tokio::process::Command;
+
+/// Runs the `congress` binary against `server`, returning its stdout
+async fn congress(server: &MockServer, args: &[&str]) -> String {
+    let config = write_config(server);
+    let output = Command::new(env!("CARGO_BIN_EXE_congress"))
+        .env_remove("CONGRESS_API_KEY")
+        .arg("--config")
+        .arg(&config)
+        .args(args)
+        .output()
+        .await
+        .unwrap();
+    std::fs::remove_file(config).unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn write_config(server: &MockServer) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "congress-cli-{}-{}.toml",
+        std::process::id(),
+        server.url().port().unwrap()
+    ));
+    let config = format!("api_key = \"test\"\nbase_url = \"{}\"\n", server.url());
+    std::fs::write(&path, config).unwrap();
+    path
+}
+
+#[tokio::test]
+async fn bills_json() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let out = congress(
+        &server,
+        &[
+            "--format",
+            "json",
+            "bills",
+            "--congress",
+            "118",
+            "--limit",
+            "2",
+        ],
+    )
+    .await;
+
+    let bills: Vec<serde_json::Value> = serde_json::from_str(&out).unwrap();
+    assert_eq!(bills.len(), 2);
+    assert_eq!(bills[0]["number"], "3746");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn all_actions_csv() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let out = congress(
+        &server,
+        &[
+            "--format", "csv", "bill", "118", "hr", "3746", "actions", "--limit", "2", "--all",
+        ],
+    )
+    .await;
+
+    let mut lines = out.lines();
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("action_date,action_code,type"));
+    assert_eq!(lines.count(), 6);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn all_actions_json() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let out = congress(
+        &server,
+        &[
+            "--format", "json", "bill", "118", "hr", "3746", "actions", "--limit", "2", "--all",
+        ],
+    )
+    .await;
+
+    let actions: Vec<serde_json::Value> = serde_json::from_str(&out).unwrap();
+    assert_eq!(actions.len(), 6);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn limit_needs_resource() {
+    let output = Command::new(env!("CARGO_BIN_EXE_congress"))
+        .args(["bill", "118", "hr", "3746", "--limit", "2"])
+        .output()
+        .await
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<RESOURCE>"));
+}
+
+#[tokio::test]
+async fn bill_table() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let out = congress(&server, &["bill", "118", "hr", "3746"]).await;
+
+    let mut lines = out.lines();
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("congress  type  number  title"));
+    assert!(lines
+        .next()
+        .unwrap()
+        .contains("Fiscal Responsibility Act of 2023"));
+    assert_eq!(lines.next(), None);
+
+    Ok(())
+}
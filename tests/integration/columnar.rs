@@ -0,0 +1,130 @@
+use super::fixture;
+use arrow_array::{
+    cast::AsArray,
+    types::{Date32Type, Int8Type, UInt32Type},
+    Array, ArrayAccessor, ArrowPrimitiveType, RecordBatch, StringArray,
+};
+use congress::{
+    bill::{actions::ActionsResponse, cosponsors::CosponsorsResponse, BillResponse},
+    bill_id::BillId,
+    bill_type::BillType,
+    bills::BillsResponse,
+    columnar::{rows_for, write_parquet, Columnar},
+    Result,
+};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+#[test]
+fn bills() -> Result<()> {
+    let response: BillsResponse = fixture("v3_bill_118__format-json_limit-2.json");
+    let batch = Columnar::record_batch(&response.bills)?;
+    assert_eq!(batch.num_rows(), 2);
+
+    let schema = batch.schema();
+    assert_eq!(
+        schema.field_with_name("update_date").unwrap().data_type(),
+        &Date32Type::DATA_TYPE
+    );
+    let chambers = batch
+        .column_by_name("origin_chamber")
+        .unwrap()
+        .as_dictionary::<Int8Type>();
+    let names = chambers.downcast_dict::<StringArray>().unwrap();
+    assert_eq!(names.value(0), "House");
+
+    Ok(())
+}
+
+#[test]
+fn actions_and_sponsors() -> Result<()> {
+    let response: ActionsResponse =
+        fixture("v3_bill_118_hr_3746_actions__format-json_limit-2_offset-2.json");
+    let bill = BillId::new(118, BillType::House, 3746);
+    let batch = Columnar::record_batch(&rows_for(bill, &response.actions))?;
+    let schema = batch.schema();
+    let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(names[..3], ["congress", "type", "number"]);
+    assert_eq!(
+        batch
+            .column_by_name("type")
+            .unwrap()
+            .as_string::<i32>()
+            .value(0),
+        "HR"
+    );
+    assert_eq!(
+        batch
+            .column_by_name("number")
+            .unwrap()
+            .as_string::<i32>()
+            .value(1),
+        "3746"
+    );
+    assert_eq!(
+        batch
+            .column_by_name("action_type")
+            .unwrap()
+            .as_string::<i32>()
+            .value(0),
+        serde_json::to_value(response.actions[0].action_type).unwrap()
+    );
+    let chambers = batch
+        .column_by_name("chamber")
+        .unwrap()
+        .as_dictionary::<Int8Type>();
+    assert_eq!(chambers.len(), 2);
+    let rolls = batch
+        .column_by_name("roll_numbers")
+        .unwrap()
+        .as_string::<i32>();
+    assert!(rolls.is_null(0));
+    assert_eq!(rolls.value(1), "146");
+
+    let response: BillResponse = fixture("v3_bill_118_hr_3746__format-json.json");
+    let sponsors = response.bill.sponsors.unwrap();
+    let batch = Columnar::record_batch(&rows_for(bill, &sponsors))?;
+    assert_eq!(batch.num_rows(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn parquet_round_trip() -> Result<()> {
+    let response: BillsResponse = fixture("v3_bill_118__format-json_limit-2.json");
+    let bytes = write_parquet(&response.bills, vec![])?;
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(bytes))
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0], Columnar::record_batch(&response.bills)?);
+
+    Ok(())
+}
+
+#[test]
+fn cosponsors() -> Result<()> {
+    let response: CosponsorsResponse = fixture("v3_bill_118_hr_3746_cosponsors__format-json.json");
+    let bill = BillId::new(118, BillType::House, 3746);
+    let batch = Columnar::record_batch(&rows_for(bill, &response.cosponsors))?;
+    assert_eq!(batch.num_rows(), 1);
+    let congress = batch
+        .column_by_name("congress")
+        .unwrap()
+        .as_primitive::<UInt32Type>();
+    assert_eq!(congress.value(0), 118);
+    assert!(batch.column_by_name("middle_name").unwrap().is_null(0));
+    assert!(batch
+        .column_by_name("sponsorship_withdrawn_date")
+        .unwrap()
+        .is_null(0));
+    let original = batch
+        .column_by_name("is_original_cosponsor")
+        .unwrap()
+        .as_boolean();
+    assert!(!original.value(0));
+
+    Ok(())
+}
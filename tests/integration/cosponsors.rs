@@ -0,0 +1,160 @@
+use chrono::NaiveDate;
+use congress::{
+    bill::cosponsors::{CosponsorsResponse, TimelinePoint},
+    party::Party,
+    state::State,
+};
+use serde_json::{json, Value};
+
+fn cosponsor(id: &str, party: &str, state: &str, original: bool, date: &str) -> Value {
+    json!({
+        "bioguideId": id,
+        "district": 1,
+        "firstName": "First",
+        "fullName": format!("Rep. {id} [{party}-{state}-1]"),
+        "isOriginalCosponsor": original,
+        "lastName": id,
+        "party": party,
+        "sponsorshipDate": date,
+        "state": state,
+        "url": format!("https://api.congress.gov/v3/member/{id}?format=json"),
+    })
+}
+
+/// The H.R. 3746 fixture plus three more cosponsors, one of whom withdrew
+fn response() -> CosponsorsResponse {
+    let mut body: Value = super::fixture("v3_bill_118_hr_3746_cosponsors__format-json.json");
+
+    let mut withdrawn = cosponsor("W000001", "R", "TX", true, "2023-05-29");
+    withdrawn["sponsorshipWithdrawnDate"] = json!("2023-06-01");
+    let cosponsors = body["cosponsors"].as_array_mut().unwrap();
+    cosponsors.push(cosponsor("R000001", "R", "NJ", true, "2023-05-29"));
+    cosponsors.push(cosponsor("I000001", "I", "VT", false, "2023-05-31"));
+    cosponsors.push(withdrawn);
+
+    serde_json::from_value(body).unwrap()
+}
+
+fn date(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+#[test]
+fn withdrawn() {
+    let response = response();
+    assert_eq!(response.current().count(), 3);
+    let withdrawn: Vec<_> = response.withdrawn().collect();
+    assert_eq!(withdrawn.len(), 1);
+    assert!(withdrawn[0].is_withdrawn());
+    assert_eq!(
+        withdrawn[0].sponsorship_withdrawn_date,
+        Some(date("2023-06-01"))
+    );
+}
+
+#[test]
+fn originals() {
+    let response = response();
+    let original: Vec<&str> = response
+        .original()
+        .map(|c| c.bioguide_id.as_str())
+        .collect();
+    assert_eq!(original, ["R000001"]);
+    assert_eq!(response.later().count(), 2);
+}
+
+#[test]
+fn counts() {
+    let response = response();
+    let by_party: Vec<_> = response.count_by_party().into_iter().collect();
+    assert_eq!(
+        by_party,
+        [
+            (Party::Democratic, 1),
+            (Party::Republican, 1),
+            (Party::Independent, 1)
+        ]
+    );
+    let by_state = response.count_by_state();
+    assert_eq!(by_state[&State::NewJersey], 2);
+    assert!(!by_state.contains_key(&State::Texas));
+}
+
+#[test]
+fn bipartisan_ratio() {
+    let response = response();
+    let ratio = response.bipartisan_ratio(&Party::Republican).unwrap();
+    assert!((ratio - 2.0 / 3.0).abs() < f64::EPSILON);
+
+    let empty: CosponsorsResponse =
+        serde_json::from_value(json!({ "cosponsors": [], "pagination": { "count": 0 } })).unwrap();
+    assert_eq!(empty.bipartisan_ratio(&Party::Republican), None);
+}
+
+#[test]
+fn timeline() {
+    let point = |d, cosponsors| TimelinePoint {
+        date: date(d),
+        cosponsors,
+    };
+    assert_eq!(
+        response().timeline(),
+        [
+            point("2023-05-29", 2),
+            point("2023-05-31", 4),
+            point("2023-06-01", 3),
+        ]
+    );
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn send_all() -> congress::Result<()> {
+    use congress::{bill_type::BillType, prelude::*, testing::Fixture, testing::MockServer};
+
+    let dir = std::env::temp_dir().join(format!("congress-cosponsors-{}", std::process::id()));
+    let path = "/v3/bill/118/hr/3746/cosponsors";
+    let next = format!("https://api.congress.gov{path}?offset=1&limit=1&format=json");
+    let pages = [
+        (
+            "format=json&limit=1",
+            json!({
+                "cosponsors": [cosponsor("R000001", "R", "NJ", true, "2023-05-29")],
+                "pagination": { "count": 2, "next": next },
+            }),
+        ),
+        (
+            "format=json&limit=1&offset=1",
+            json!({
+                "cosponsors": [cosponsor("D000001", "D", "NJ", false, "2023-05-31")],
+                "pagination": { "count": 2 },
+            }),
+        ),
+    ];
+    for (query, body) in pages {
+        Fixture {
+            path: path.to_string(),
+            query: query.to_string(),
+            status: 200,
+            body,
+        }
+        .save(&dir)?;
+    }
+
+    let server = MockServer::replay(&dir).await?;
+    let client = server.client_builder()?.build()?;
+    let response = client
+        .bill(118, BillType::House, 3746)
+        .cosponsors()
+        .limit(1)
+        .send_all()
+        .await;
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let response = response?;
+    assert_eq!(response.cosponsors.len(), 2);
+    assert_eq!(response.count_by_state()[&State::NewJersey], 2);
+    assert_eq!(response.bipartisan_ratio(&Party::Republican), Some(0.5));
+
+    Ok(())
+}
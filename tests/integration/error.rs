@@ -0,0 +1,113 @@
+use congress::{
+    bill_type::BillType,
+    error::{Error, ErrorBody},
+    transport::{async_trait, HttpRequest, HttpResponse, HttpTransport},
+    ClientBuilder, Result,
+};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+struct StaticTransport(HttpResponse);
+
+#[async_trait]
+impl HttpTransport for StaticTransport {
+    async fn get(&self, _request: HttpRequest) -> Result<HttpResponse> {
+        Ok(self.0.clone())
+    }
+}
+
+async fn error(status: u16, headers: &[(&str, &str)], body: &str) -> Error {
+    let response = HttpResponse {
+        status,
+        headers: headers
+            .iter()
+            .map(|(n, v)| (n.to_string(), v.to_string()))
+            .collect(),
+        body: body.as_bytes().to_vec(),
+    };
+    let client = ClientBuilder::new("KEY")
+        .transport(StaticTransport(response))
+        .build()
+        .unwrap();
+    client
+        .bill(118, BillType::House, 1)
+        .send()
+        .await
+        .unwrap_err()
+}
+
+#[tokio::test]
+async fn not_found() {
+    let error = error(
+        404,
+        &[],
+        r#"{"error":{"code":"NOT_FOUND","message":"Unknown bill"}}"#,
+    )
+    .await;
+    match &error {
+        Error::NotFound { path, body } => {
+            assert_eq!(path, "/v3/bill/118/hr/1");
+            assert_eq!(body.code(), Some("NOT_FOUND"));
+            assert_eq!(body.message(), "Unknown bill");
+        }
+        e => panic!("unexpected error {e:?}"),
+    }
+    assert_eq!(error.status(), Some(404));
+    assert!(!error.is_retryable());
+}
+
+#[tokio::test]
+async fn rate_limited() {
+    let body =
+        r#"{"error":{"code":"OVER_RATE_LIMIT","message":"You have exceeded your rate limit."}}"#;
+    let error = error(429, &[("retry-after", "120")], body).await;
+    assert!(matches!(
+        error,
+        Error::RateLimited { retry_after: Some(d), .. } if d == Duration::from_secs(120)
+    ));
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    let body =
+        r#"{"error":{"code":"API_KEY_INVALID","message":"An invalid api_key was supplied."}}"#;
+    let error = error(403, &[], body).await;
+    assert!(matches!(error, Error::Unauthorized { status: 403, .. }));
+    assert!(!error.is_retryable());
+}
+
+#[tokio::test]
+async fn raw_server_error() {
+    let error = error(503, &[], "<html><body>Service Unavailable</body></html>").await;
+    match &error {
+        Error::ServerError {
+            status: 503,
+            body: ErrorBody::Raw(raw),
+            ..
+        } => assert!(raw.contains("Service Unavailable")),
+        e => panic!("unexpected error {e:?}"),
+    }
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn other_status() {
+    let error = error(
+        400,
+        &[],
+        r#"{"error":{"code":"BAD","message":"Bad request"}}"#,
+    )
+    .await;
+    assert!(matches!(error, Error::Congress { status: 400, .. }));
+    assert_eq!(
+        error.to_string(),
+        "Congress API Error (400) on /v3/bill/118/hr/1: Bad request"
+    );
+}
+
+#[tokio::test]
+async fn invalid_json() {
+    let error = error(200, &[], "<html></html>").await;
+    assert!(matches!(error, Error::JsonParseError { ref path, .. } if path == "/v3/bill/118/hr/1"));
+}
@@ -0,0 +1,88 @@
+use congress::{
+    bill::{actions::Action, cosponsors::Cosponsor},
+    bill_type::BillType,
+    bills::Bill,
+    export::{export_pages, CsvWriter, NdjsonWriter, RecordWriter, Tabular},
+    prelude::*,
+    testing::MockServer,
+    Result,
+};
+
+#[tokio::test]
+async fn csv() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = server.client_builder()?.build()?;
+
+    let bills = client.bills().congress(118).limit(2).send().await?;
+    let mut writer = CsvWriter::new(vec![]);
+    writer.write_all(&bills)?;
+    let csv = String::from_utf8(writer.into_inner()?).unwrap();
+
+    let mut lines = csv.lines();
+    assert_eq!(lines.next().unwrap(), Bill::COLUMNS.join(","));
+    let first = lines.next().unwrap();
+    assert!(first.starts_with("118,HR,3746,Fiscal Responsibility Act of 2023,House,H,2023-06-03,"));
+    assert!(first.contains("Became Public Law No: 118-5."));
+    assert_eq!(lines.count(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paged_ndjson() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = server.client_builder()?.build()?;
+
+    let first = client
+        .bill(118, BillType::House, 3746)
+        .actions()
+        .limit(2)
+        .send()
+        .await?;
+    let mut writer = NdjsonWriter::new(vec![]);
+    assert_eq!(export_pages(&client, first, &mut writer).await?, 6);
+
+    let ndjson = String::from_utf8(writer.into_inner()?).unwrap();
+    let actions: Vec<Action> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(actions.len(), 6);
+    assert_eq!(actions[5].action_code.as_deref(), Some("H37100"));
+
+    Ok(())
+}
+
+#[test]
+fn empty_csv() -> Result<()> {
+    let writer: CsvWriter<_, Action> = CsvWriter::new(vec![]);
+    let csv = String::from_utf8(writer.into_inner()?).unwrap();
+    assert_eq!(csv.trim_end(), Action::COLUMNS.join(","));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cosponsors_csv() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = server.client_builder()?.build()?;
+
+    let cosponsors = client
+        .bill(118, BillType::House, 3746)
+        .cosponsors()
+        .send()
+        .await?;
+    let mut writer = CsvWriter::new(vec![]);
+    writer.write_all(&cosponsors)?;
+    let csv = String::from_utf8(writer.into_inner()?).unwrap();
+
+    let mut lines = csv.lines();
+    assert_eq!(lines.next().unwrap(), Cosponsor::COLUMNS.join(","));
+    assert_eq!(
+        lines.next().unwrap(),
+        "G000583,\"Rep. Gottheimer, Josh [D-NJ-5]\",Josh,,Gottheimer,D,NJ,5,false,2023-05-31,,\
+         https://api.congress.gov/v3/member/G000583?format=json"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use super::fixture;
+use chrono::{DateTime, Utc};
+use congress::{
+    bill::{actions::ActionsResponse, text::TextResponse, BillResponse},
+    bill_id::BillId,
+    bill_type::BillType,
+    feed::{Entry, Feed},
+    watch::Event,
+};
+use quick_xml::events::Event as XmlEvent;
+
+const HR_3746: BillId = BillId {
+    congress: 118,
+    bill_type: BillType::House,
+    number: 3746,
+};
+
+fn events() -> Vec<Event> {
+    let actions: ActionsResponse =
+        fixture("v3_bill_118_hr_3746_actions__format-json_limit-2_offset-2.json");
+    let text: TextResponse = fixture("v3_bill_118_hr_3746_text__format-json.json");
+    let bill: BillResponse = fixture("v3_bill_118_hr_3746__format-json.json");
+
+    let mut events: Vec<Event> = actions
+        .actions
+        .into_iter()
+        .map(|action| Event::NewAction {
+            bill: HR_3746,
+            action,
+        })
+        .collect();
+    events.push(Event::NewTextVersion {
+        bill: HR_3746,
+        version: text.text_versions[0].clone(),
+    });
+    events.push(Event::BecameLaw {
+        bill: HR_3746,
+        law: bill.bill.laws.unwrap()[0].clone(),
+        date: bill.bill.latest_action.action_date,
+    });
+    events
+}
+
+/// The text of every `name` element, failing on malformed XML
+fn texts(xml: &str, name: &[u8]) -> Vec<String> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut texts = vec![];
+    let mut inside = false;
+    loop {
+        match reader.read_event().unwrap() {
+            XmlEvent::Start(e) => inside = e.name().as_ref() == name,
+            XmlEvent::Text(t) if inside => texts.push(t.unescape().unwrap().into_owned()),
+            XmlEvent::End(_) => inside = false,
+            XmlEvent::Eof => return texts,
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn web_url() {
+    assert_eq!(
+        HR_3746.web_url().as_str(),
+        "https://www.congress.gov/bill/118th-congress/house-bill/3746"
+    );
+    let resolution = BillId::new(111, BillType::SenateJointResolution, 2);
+    assert_eq!(
+        resolution.web_url().as_str(),
+        "https://www.congress.gov/bill/111th-congress/senate-joint-resolution/2"
+    );
+    assert_eq!(resolution.citation(), "S.J.Res. 2");
+}
+
+#[test]
+fn entries() {
+    let seen: DateTime<Utc> = "2023-07-01T00:00:00Z".parse().unwrap();
+    let mut feed = Feed::new("H.R. 3746", HR_3746.web_url());
+    feed.extend(&events(), seen);
+
+    let entries = feed.entries();
+    assert_eq!(entries.len(), 4);
+    assert!(entries.windows(2).all(|w| w[0].updated >= w[1].updated));
+    assert_eq!(feed.updated(), entries[0].updated);
+
+    let law = entries
+        .iter()
+        .find(|e| e.id.ends_with(":law:118-5"))
+        .unwrap();
+    assert_eq!(law.title, "H.R. 3746: Became Public Law 118-5");
+    assert_eq!(law.id, "tag:api.congress.gov,2023:hr3746-118:law:118-5");
+
+    let action = entries.iter().find(|e| e.id.contains(":action:")).unwrap();
+    assert_eq!(
+        action.link.as_str(),
+        "https://www.congress.gov/bill/118th-congress/house-bill/3746/all-actions"
+    );
+
+    // The same event always gets the same ID
+    assert_eq!(
+        Entry::new(&events()[0], seen),
+        Entry::new(&events()[0], Utc::now())
+    );
+}
+
+#[test]
+fn referrals_on_one_day() {
+    let referral = |committee: &str| Event::NewAction {
+        bill: HR_3746,
+        action: serde_json::from_value(serde_json::json!({
+            "actionCode": "H11100",
+            "actionDate": "2023-05-29",
+            "sourceSystem": { "code": 2, "name": "House floor actions" },
+            "text": format!("Referred to the Committee on {committee}."),
+            "type": "IntroReferral",
+        }))
+        .unwrap(),
+    };
+    let seen = Utc::now();
+    let budget = Entry::new(&referral("the Budget"), seen);
+    let rules = Entry::new(&referral("Rules"), seen);
+
+    assert!(budget.id.contains(":action:2023-05-29:H11100:"));
+    assert_ne!(budget.id, rules.id);
+    assert_eq!(budget.id, Entry::new(&referral("the Budget"), seen).id);
+}
+
+#[test]
+fn rss() {
+    let mut feed = Feed::new("H.R. 3746 & friends", HR_3746.web_url());
+    feed.extend(&events(), Utc::now());
+    let xml = feed.rss();
+
+    assert_eq!(texts(&xml, b"title")[0], "H.R. 3746 & friends");
+    assert_eq!(texts(&xml, b"guid").len(), 4);
+    assert_eq!(texts(&xml, b"pubDate")[0], "Sat, 3 Jun 2023 00:00:00 +0000");
+}
+
+#[test]
+fn atom() {
+    let mut feed = Feed::new("H.R. 3746", HR_3746.web_url());
+    feed.extend(&events(), Utc::now());
+    let xml = feed.atom();
+
+    let ids = texts(&xml, b"id");
+    assert_eq!(ids.len(), 5);
+    assert_eq!(ids[0], HR_3746.web_url().as_str());
+    assert_eq!(texts(&xml, b"updated")[0], "2023-06-03T00:00:00+00:00");
+}
+
+#[test]
+fn empty() {
+    let feed = Feed::new("Nothing yet", HR_3746.web_url());
+    assert_eq!(texts(&feed.rss(), b"item").len(), 0);
+    assert_eq!(
+        texts(&feed.atom(), b"updated")[0],
+        "1970-01-01T00:00:00+00:00"
+    );
+}
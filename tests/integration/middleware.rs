@@ -0,0 +1,60 @@
+use congress::{
+    middleware::{Middleware, RequestContext, ResponseContext},
+    prelude::*,
+    testing::MockServer,
+    Result,
+};
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc, Mutex,
+};
+
+#[derive(Debug, Default)]
+struct CorrelationId {
+    next: AtomicU32,
+}
+
+impl Middleware for CorrelationId {
+    fn before_request(&self, request: &mut RequestContext) {
+        let id = self.next.fetch_add(1, Ordering::SeqCst);
+        request.insert_header("X-Correlation-Id", id.to_string());
+    }
+}
+
+#[tokio::test]
+async fn hooks() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let seen = Arc::new(Mutex::new(vec![]));
+    let log = seen.clone();
+
+    let client = server
+        .client_builder_with_key("SECRET")?
+        .middleware(CorrelationId::default())
+        .after_response(move |response: &ResponseContext| {
+            log.lock().unwrap().push((
+                response.request.path.clone(),
+                response.request.parameters.and_then(|p| p.offset),
+                response
+                    .request
+                    .header("X-Correlation-Id")
+                    .map(String::from),
+                response.status,
+                response.bytes,
+            ));
+        })
+        .build()?;
+
+    let bills = client.bills().congress(118).limit(2).send().await?;
+    client.next(&bills).await?;
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[0].0, "/v3/bill/118");
+    assert_eq!(seen[1].1, Some(2));
+    assert_eq!(seen[1].2.as_deref(), Some("1"));
+    assert_eq!(seen[0].3, Some(200));
+    assert!(seen[0].4 > 0);
+    assert!(!format!("{seen:?}").contains("SECRET"));
+
+    Ok(())
+}
@@ -1,2 +1,45 @@
+mod action_code;
+mod actions;
+mod api_key;
 mod bill;
+mod bill_diff;
 mod bills;
+#[cfg(all(feature = "cli", feature = "testing"))]
+mod cli;
+#[cfg(feature = "parquet")]
+mod columnar;
+mod cosponsors;
+mod error;
+#[cfg(all(feature = "export", feature = "testing"))]
+mod export;
+#[cfg(feature = "feed")]
+mod feed;
+#[cfg(feature = "testing")]
+mod middleware;
+mod party;
+#[cfg(feature = "testing")]
+mod replay;
+mod serialize;
+mod state;
+mod status;
+#[cfg(feature = "store")]
+mod store;
+mod text;
+#[cfg(all(feature = "tracing", feature = "testing"))]
+mod tracing;
+mod transport;
+mod version_code;
+mod votes;
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(all(feature = "webhook", feature = "testing"))]
+mod webhook;
+#[cfg(all(feature = "xml", feature = "testing"))]
+mod xml;
+
+/// The body of a recorded response in `tests/fixtures`
+pub(crate) fn fixture<T: serde::de::DeserializeOwned>(name: &str) -> T {
+    let file = std::fs::read(format!("tests/fixtures/{name}")).unwrap();
+    let fixture: serde_json::Value = serde_json::from_slice(&file).unwrap();
+    serde_json::from_value(fixture["body"].clone()).unwrap()
+}
@@ -0,0 +1,16 @@
+use congress::party::Party;
+
+#[test]
+fn parties() {
+    let independent_democrat: Party = serde_json::from_str("\"ID\"").unwrap();
+    assert_eq!(independent_democrat, Party::IndependentDemocrat);
+    assert_eq!(independent_democrat.name(), Some("Independent Democrat"));
+    assert_eq!(Party::from("democrat"), Party::Democratic);
+    assert_eq!(Party::from("Republican"), Party::Republican);
+    assert_eq!(serde_json::to_string(&Party::Libertarian).unwrap(), "\"L\"");
+
+    let unknown: Party = serde_json::from_str("\"AIP\"").unwrap();
+    assert_eq!(unknown, Party::Other(String::from("AIP")));
+    assert_eq!(unknown.name(), None);
+    assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"AIP\"");
+}
@@ -0,0 +1,112 @@
+use congress::{
+    bill::actions::ActionsResponse,
+    bill_type::BillType,
+    error::Error,
+    prelude::*,
+    testing::{Fixture, MockServer},
+    version_code::TextVersionCode,
+    Client, Result,
+};
+
+fn client(server: &MockServer) -> Result<Client> {
+    server.client_builder()?.build()
+}
+
+#[tokio::test]
+async fn bills() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = client(&server)?;
+
+    let bills = client.bills().congress(118).limit(2).send().await?;
+    assert_eq!(bills.bills.len(), 2);
+    assert_eq!(bills.bills[0].number, "3746");
+
+    let next = client.next(&bills).await?.unwrap();
+    assert_eq!(next.bills[0].number, "3745");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bill() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = client(&server)?;
+    let handler = client.bill(118, BillType::House, 3746);
+
+    let bill = handler.send().await?.bill;
+    assert_eq!(bill.title, "Fiscal Responsibility Act of 2023");
+
+    let actions = handler.actions().limit(2).send().await?;
+    let next: ActionsResponse = client.next(&actions).await?.unwrap();
+    assert!(next.actions[1].recorded_votes.is_some());
+
+    let amendments = handler.amendments().send().await?;
+    assert_eq!(amendments.amendments[0].number, "150");
+
+    let committees = handler.committees().send().await?;
+    assert_eq!(committees.committees[0].committee.system_code, "hsbu00");
+
+    let related = handler.related_bills().send().await?;
+    assert_eq!(related.related_bills[0].number, 2811);
+
+    let summaries = handler.summaries().send().await?;
+    assert_eq!(summaries.summaries.len(), 1);
+
+    let text = handler.text().send().await?;
+    assert!(text.version(TextVersionCode::PublicLaw).is_some());
+
+    let titles = handler.titles().send().await?;
+    assert_eq!(
+        titles.titles[0].bill_text_version_code,
+        TextVersionCode::EnrolledBill
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_fixture() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = client(&server)?;
+
+    let error = client
+        .bill(118, BillType::Senate, 1)
+        .send()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::NotFound { ref path, .. } if path == "/v3/bill/118/s/1"));
+
+    Ok(())
+}
+
+#[test]
+fn fixture_names() {
+    assert_eq!(
+        Fixture::file_name("/v3/bill/118", "format=json&limit=2"),
+        "v3_bill_118__format-json_limit-2.json"
+    );
+}
+
+#[tokio::test]
+async fn raw_and_extra_fields() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = client(&server)?;
+    let handler = client.bill(118, BillType::House, 3746);
+
+    let raw = handler.send_raw().await?;
+    assert_eq!(raw["bill"]["number"], "3746");
+
+    let cosponsors = handler.cosponsors().send_raw().await?;
+    assert!(cosponsors["cosponsors"][0]["isOriginalCosponsor"].is_boolean());
+    let cosponsors = handler.cosponsors().send().await?;
+    assert_eq!(cosponsors.cosponsors[0].bioguide_id, "G000583");
+    assert!(!cosponsors.cosponsors[0].is_original_cosponsor);
+
+    #[cfg(feature = "extra-fields")]
+    {
+        let actions = handler.actions().limit(2).send().await?;
+        assert!(actions.actions[0].extra.is_empty());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use congress::{
+    bill::{
+        actions::ActionsResponse, amendments::AmendmentsResponse, committees::CommitteesResponse,
+        cosponsors::CosponsorsResponse, related_bills::RelatedBillsResponse,
+        summaries::SummariesResponse, text::TextResponse, titles::TitlesResponse, BillResponse,
+    },
+    bills::BillsResponse,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+/// Deserializes a fixture, serializes it again and checks nothing was lost
+fn round_trip<T>(fixture: &str) -> serde_json::Value
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    let mut body: serde_json::Value = super::fixture(fixture);
+    body.as_object_mut().unwrap().remove("request");
+    let original: T = serde_json::from_value(body.clone()).unwrap();
+
+    let json = serde_json::to_value(&original).unwrap();
+    let decoded: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(original, decoded);
+    assert_matches(&json, &body, "$");
+    json
+}
+
+/// Checks every serialized value against the API's. Unset options may be
+/// written as null where the API left the field out. Without extra-fields
+/// the API may also carry fields the models don't cover.
+fn assert_matches(json: &serde_json::Value, api: &serde_json::Value, path: &str) {
+    use serde_json::Value;
+
+    match (json, api) {
+        (Value::Object(json), Value::Object(api)) => {
+            for (key, value) in json {
+                match api.get(key) {
+                    Some(expected) => assert_matches(value, expected, &format!("{path}.{key}")),
+                    None => assert!(value.is_null(), "{path}.{key} is not in the API response"),
+                }
+            }
+            if cfg!(feature = "extra-fields") {
+                for key in api.keys() {
+                    assert!(json.contains_key(key), "{path}.{key} was lost");
+                }
+            }
+        }
+        (Value::Array(json), Value::Array(api)) => {
+            assert_eq!(json.len(), api.len(), "{path} changed length");
+            for (i, (value, expected)) in json.iter().zip(api).enumerate() {
+                assert_matches(value, expected, &format!("{path}[{i}]"));
+            }
+        }
+        _ => assert_eq!(json, api, "{path} changed"),
+    }
+}
+
+#[test]
+fn bills() {
+    let json = round_trip::<BillsResponse>("v3_bill_118__format-json_limit-2.json");
+    assert_eq!(json["bills"][0]["type"], "HR");
+    assert!(json["bills"][0]["updateDate"].is_string());
+}
+
+#[test]
+fn bill() {
+    let json = round_trip::<BillResponse>("v3_bill_118_hr_3746__format-json.json");
+    assert_eq!(json["bill"]["originChamber"], "House");
+    assert_eq!(json["bill"]["latestAction"]["actionDate"], "2023-06-03");
+    assert_eq!(
+        json["bill"]["cboCostEstimates"][0]["pubDate"],
+        "2023-05-30T20:00:00Z"
+    );
+    assert_eq!(
+        json["bill"]["cosponsors"]["countIncludingWithdrawnCosponsors"],
+        1
+    );
+}
+
+#[test]
+fn actions() {
+    round_trip::<ActionsResponse>("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    let json = round_trip::<ActionsResponse>(
+        "v3_bill_118_hr_3746_actions__format-json_limit-2_offset-2.json",
+    );
+    assert!(json["actions"][1]["recordedVotes"].is_array());
+}
+
+#[test]
+fn bill_parts() {
+    round_trip::<AmendmentsResponse>("v3_bill_118_hr_3746_amendments__format-json.json");
+    round_trip::<CommitteesResponse>("v3_bill_118_hr_3746_committees__format-json.json");
+    round_trip::<RelatedBillsResponse>("v3_bill_118_hr_3746_relatedbills__format-json.json");
+    round_trip::<SummariesResponse>("v3_bill_118_hr_3746_summaries__format-json.json");
+    round_trip::<TitlesResponse>("v3_bill_118_hr_3746_titles__format-json.json");
+
+    let json = round_trip::<CosponsorsResponse>("v3_bill_118_hr_3746_cosponsors__format-json.json");
+    assert_eq!(json["cosponsors"][0]["isOriginalCosponsor"], false);
+    assert!(json["cosponsors"][0]["sponsorshipWithdrawnDate"].is_null());
+
+    let json = round_trip::<TextResponse>("v3_bill_118_hr_3746_text__format-json.json");
+    assert!(json["textVersions"][0]["formats"][0]["type"].is_string());
+}
@@ -0,0 +1,73 @@
+use congress::{
+    bill::Sponsor,
+    party::Party,
+    state::{District, State},
+};
+use serde_json::json;
+
+#[test]
+fn states() {
+    let new_jersey: State = serde_json::from_str("\"NJ\"").unwrap();
+    assert_eq!(new_jersey, State::NewJersey);
+    assert_eq!(new_jersey.name(), Some("New Jersey"));
+    assert_eq!(new_jersey.fips(), Some(34));
+    assert_eq!("puerto rico".parse(), Ok(State::PuertoRico));
+    assert_eq!(
+        serde_json::to_string(&State::NorthernMarianaIslands).unwrap(),
+        "\"MP\""
+    );
+
+    assert_eq!(State::ALL.len(), 56);
+    assert_eq!(State::ALL.iter().filter(|s| s.is_state()).count(), 50);
+    assert!(!State::Guam.is_state());
+    assert!("XX".parse::<State>().is_err());
+
+    let unknown: State = serde_json::from_str("\"XX\"").unwrap();
+    assert_eq!(unknown, State::Other(String::from("XX")));
+    assert_eq!(unknown.name(), None);
+    assert_eq!(unknown.fips(), None);
+    assert!(!unknown.is_state());
+    assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"XX\"");
+}
+
+#[test]
+fn districts() {
+    let at_large: District = serde_json::from_str("0").unwrap();
+    assert!(at_large.is_at_large());
+    assert_eq!(at_large.number(), None);
+    assert_eq!(at_large.to_string(), "at-large");
+    assert_eq!(serde_json::to_string(&at_large).unwrap(), "0");
+
+    let third = District::from(3);
+    assert_eq!(third, District::Numbered(3));
+    assert_eq!(u32::from(third), 3);
+}
+
+fn sponsor(state: &str, district: Option<u32>) -> Sponsor {
+    let mut sponsor = json!({
+        "bioguideId": "A000001",
+        "firstName": "First",
+        "fullName": "Member",
+        "isByRequest": "N",
+        "lastName": "Member",
+        "party": "R",
+        "state": state,
+        "url": "https://api.congress.gov/v3/member/A000001?format=json",
+    });
+    if let Some(district) = district {
+        sponsor["district"] = json!(district);
+    }
+    serde_json::from_value(sponsor).unwrap()
+}
+
+#[test]
+fn sponsors() {
+    let representative = sponsor("WY", Some(0));
+    assert_eq!(representative.state, State::Wyoming);
+    assert_eq!(representative.district, Some(District::AtLarge));
+    assert_eq!(representative.party, Party::Republican);
+
+    let senator = sponsor("Wyoming", None);
+    assert_eq!(senator.state, State::Wyoming);
+    assert_eq!(senator.district, None);
+}
@@ -0,0 +1,94 @@
+use congress::{
+    action_code::{ActionCategory, ActionCodeInfo},
+    bill::actions::ActionsResponse,
+    status::Stage,
+};
+use serde_json::json;
+
+fn action(
+    date: &str,
+    code: Option<&str>,
+    source: u32,
+    kind: &str,
+    text: &str,
+) -> serde_json::Value {
+    json!({
+        "actionCode": code,
+        "actionDate": date,
+        "sourceSystem": { "code": source, "name": "" },
+        "text": text,
+        "type": kind,
+    })
+}
+
+#[test]
+fn status_from_actions() {
+    // Newest first, as the API returns them
+    let response: ActionsResponse = serde_json::from_value(json!({
+        "actions": [
+            action("2023-06-03", Some("E40000"), 9, "BecameLaw", "Became Public Law No: 118-5."),
+            action("2023-06-03", Some("E30000"), 9, "President", "Signed by President."),
+            action("2023-06-02", Some("E20000"), 9, "President", "Presented to President."),
+            action("2023-06-01", Some("17000"), 0, "Floor", "Passed Senate without amendment by Yea-Nay Vote. 63 - 36."),
+            action("2023-05-31", None, 2, "Floor", "On passage Passed by the Yeas and Nays: 314 - 117."),
+            action("2023-05-29", Some("H11100"), 2, "IntroReferral", "Referred to the House Committee on the Budget."),
+            action("2023-05-29", Some("Intro-H"), 2, "IntroReferral", "Introduced in House"),
+            action("2023-05-29", Some("1000"), 9, "IntroReferral", "Introduced in House"),
+        ],
+        "pagination": { "count": 8 },
+    }))
+    .unwrap();
+
+    let status = response.status().unwrap();
+    assert_eq!(status.stage, Stage::BecameLaw);
+    assert_eq!(
+        status.timeline.iter().map(|t| t.stage).collect::<Vec<_>>(),
+        vec![
+            Stage::Introduced,
+            Stage::Referred,
+            Stage::PassedHouse,
+            Stage::PassedSenate,
+            Stage::ToPresident,
+            Stage::BecameLaw,
+        ]
+    );
+    assert_eq!(
+        status.reached(Stage::PassedSenate),
+        "2023-06-01".parse().ok()
+    );
+    assert_eq!(status.reached(Stage::Vetoed), None);
+}
+
+#[test]
+fn stage_from_text() {
+    assert_eq!(
+        Stage::from_text(
+            "Placed on the Union Calendar, Calendar No. 12. Reported by the Committee on Rules."
+        ),
+        Some(Stage::Reported)
+    );
+    assert_eq!(
+        Stage::from_text("Vetoed by President."),
+        Some(Stage::Vetoed)
+    );
+    assert_eq!(
+        Stage::from_text("Motion to reconsider laid on the table"),
+        None
+    );
+}
+
+#[test]
+fn stage_from_action_code() {
+    assert_eq!(Stage::from_action_code("H11100"), Some(Stage::Referred));
+    assert_eq!(Stage::from_action_code("H37300"), Some(Stage::PassedHouse));
+    assert_eq!(Stage::from_action_code("17000"), Some(Stage::PassedSenate));
+    assert_eq!(Stage::from_action_code("E50000"), Some(Stage::Vetoed));
+    assert_eq!(Stage::from_action_code("H12410"), None);
+    assert_eq!(Stage::from_action_code("X99999"), None);
+
+    for info in ActionCodeInfo::all() {
+        if info.category == ActionCategory::Passage {
+            assert!(Stage::from_code_info(info).is_some(), "{}", info.code);
+        }
+    }
+}
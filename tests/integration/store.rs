@@ -0,0 +1,220 @@
+use super::fixture;
+use congress::{
+    bill::{
+        actions::ActionsResponse, committees::CommitteesResponse, cosponsors::CosponsorsResponse,
+        related_bills::RelatedBillsResponse, summaries::SummariesResponse, titles::TitlesResponse,
+        BillResponse,
+    },
+    bill_id::BillId,
+    bill_type::BillType,
+    bills::BillsResponse,
+    error::Error,
+    store::Store,
+    Result,
+};
+
+fn count(store: &Store, table: &str) -> u32 {
+    store
+        .connection()
+        .query_row(&format!("SELECT count(*) FROM {table}"), [], |row| {
+            row.get(0)
+        })
+        .unwrap()
+}
+
+fn load(store: &mut Store) -> Result<()> {
+    let bills: BillsResponse = fixture("v3_bill_118__format-json_limit-2.json");
+    store.upsert_bills(&bills.bills)?;
+
+    let bill: BillResponse = fixture("v3_bill_118_hr_3746__format-json.json");
+    store.upsert_bill(&bill.bill)?;
+    let id = bill.bill.id()?;
+
+    let mut actions = vec![];
+    for page in [
+        "v3_bill_118_hr_3746_actions__format-json_limit-2.json",
+        "v3_bill_118_hr_3746_actions__format-json_limit-2_offset-2.json",
+    ] {
+        let page: ActionsResponse = fixture(page);
+        actions.extend(page.actions);
+    }
+    store.replace_actions(&id, &actions)?;
+    let cosponsors: CosponsorsResponse =
+        fixture("v3_bill_118_hr_3746_cosponsors__format-json.json");
+    store.replace_cosponsors(&id, &cosponsors.cosponsors)?;
+    let committees: CommitteesResponse =
+        fixture("v3_bill_118_hr_3746_committees__format-json.json");
+    store.replace_committees(&id, &committees.committees)?;
+    let related: RelatedBillsResponse =
+        fixture("v3_bill_118_hr_3746_relatedbills__format-json.json");
+    store.replace_related_bills(&id, &related.related_bills)?;
+    let summaries: SummariesResponse = fixture("v3_bill_118_hr_3746_summaries__format-json.json");
+    store.replace_summaries(&id, &summaries.summaries)?;
+    let titles: TitlesResponse = fixture("v3_bill_118_hr_3746_titles__format-json.json");
+    store.replace_titles(&id, &titles.titles)
+}
+
+#[test]
+fn upsert() -> Result<()> {
+    let mut store = Store::open_in_memory()?;
+    load(&mut store)?;
+    let counts: Vec<u32> = ["bills", "actions", "cosponsors", "committees", "summaries"]
+        .iter()
+        .map(|table| count(&store, table))
+        .collect();
+    assert_eq!(counts, [2, 4, 1, 1, 1]);
+    assert!(count(&store, "committee_activities") > 0);
+    assert!(count(&store, "related_bills") > 0);
+    assert!(count(&store, "titles") > 0);
+
+    // Loading the same responses again updates rows in place
+    load(&mut store)?;
+    assert_eq!(count(&store, "actions"), 4);
+
+    // Rows the API no longer returns are removed
+    let id = BillId::new(118, BillType::House, 3746);
+    store.replace_cosponsors(&id, &[])?;
+    store.replace_committees(&id, &[])?;
+    assert_eq!(count(&store, "cosponsors"), 0);
+    assert_eq!(count(&store, "committee_activities"), 0);
+    let actions: ActionsResponse = fixture("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    store.replace_actions(&id, &actions.actions)?;
+    assert_eq!(count(&store, "actions"), 2);
+
+    let (title, introduced): (String, String) = store
+        .connection()
+        .query_row(
+            "SELECT title, introduced_date FROM bills
+             WHERE congress = 118 AND bill_type = 'HR' AND number = 3746",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(title, "Fiscal Responsibility Act of 2023");
+    assert_eq!(introduced, "2023-05-29");
+
+    // The list only has the day, so it doesn't overwrite the detail's time
+    let update_date = |store: &Store| -> String {
+        store
+            .connection()
+            .query_row(
+                "SELECT update_date FROM bills
+                 WHERE congress = 118 AND bill_type = 'HR' AND number = 3746",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap()
+    };
+    assert_eq!(update_date(&store), "2023-07-18T16:15:27Z");
+    let mut bills: BillsResponse = fixture("v3_bill_118__format-json_limit-2.json");
+    store.upsert_bills(&bills.bills)?;
+    assert_eq!(update_date(&store), "2023-07-18T16:15:27Z");
+    bills.bills[0].update_date = "2023-07-19".parse().unwrap();
+    store.upsert_bills(&bills.bills)?;
+    assert_eq!(update_date(&store), "2023-07-19");
+
+    Ok(())
+}
+
+#[test]
+fn edited_action_text() -> Result<()> {
+    let mut store = Store::open_in_memory()?;
+    let bill: BillResponse = fixture("v3_bill_118_hr_3746__format-json.json");
+    store.upsert_bill(&bill.bill)?;
+    let id = bill.bill.id()?;
+
+    let mut actions: ActionsResponse =
+        fixture("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    store.replace_actions(&id, &actions.actions)?;
+    actions.actions[0].text.push_str(" (corrected)");
+    store.replace_actions(&id, &actions.actions)?;
+
+    assert_eq!(count(&store, "actions"), 2);
+    let text: String = store
+        .connection()
+        .query_row("SELECT text FROM actions WHERE position = 0", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert!(text.ends_with(" (corrected)"));
+
+    Ok(())
+}
+
+#[test]
+fn child_rows_need_their_bill() -> Result<()> {
+    let mut store = Store::open_in_memory()?;
+    let bill: BillResponse = fixture("v3_bill_118_hr_3746__format-json.json");
+    let actions: ActionsResponse = fixture("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    assert!(store
+        .replace_actions(&bill.bill.id()?, &actions.actions)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn non_numeric_bill_number() -> Result<()> {
+    let mut store = Store::open_in_memory()?;
+    let mut bills: BillsResponse = fixture("v3_bill_118__format-json_limit-2.json");
+    bills.bills[1].number = String::from("3746A");
+    match store.upsert_bills(&bills.bills) {
+        Err(Error::InvalidBillNumber { number, .. }) => assert_eq!(number, "3746A"),
+        result => panic!("unexpected result {result:?}"),
+    }
+    assert_eq!(count(&store, "bills"), 0);
+
+    Ok(())
+}
+
+#[test]
+fn checkpoints() -> Result<()> {
+    let store = Store::open_in_memory()?;
+    assert_eq!(store.checkpoint("bills-118")?, None);
+
+    store.set_checkpoint("bills-118", "2023-07-07T13:57:18Z")?;
+    store.set_checkpoint("bills-118", "2023-07-08T09:00:00Z")?;
+    assert_eq!(
+        store.checkpoint("bills-118")?.as_deref(),
+        Some("2023-07-08T09:00:00Z")
+    );
+    assert!(store.checkpoint_updated_at("bills-118")?.is_some());
+
+    let updated_at: String = store
+        .connection()
+        .query_row(
+            "SELECT updated_at FROM checkpoints WHERE name = 'bills-118'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(
+        chrono::DateTime::parse_from_rfc3339(&updated_at).is_ok() && updated_at.ends_with('Z'),
+        "{updated_at}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn timestamps_as_rfc3339() -> Result<()> {
+    let mut store = Store::open_in_memory()?;
+    load(&mut store)?;
+
+    let text = |sql: &str| -> String {
+        store
+            .connection()
+            .query_row(sql, [], |row| row.get(0))
+            .unwrap()
+    };
+    assert_eq!(
+        text("SELECT update_date FROM summaries"),
+        "2023-06-02T17:56:37Z"
+    );
+    assert_eq!(
+        text("SELECT min(date) FROM committee_activities"),
+        "2023-05-29T23:04:10Z"
+    );
+
+    Ok(())
+}
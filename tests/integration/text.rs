@@ -0,0 +1,197 @@
+use congress::{
+    bill::text::{diff::ChangeKind, BillDocument, FormatKind, TextDiff, TextResponse},
+    bill_type::BillType,
+    prelude::*,
+    ClientBuilder, Result,
+};
+
+const BILL_XML: &str = r#"<?xml version="1.0"?>
+<bill bill-stage="Introduced-in-House">
+<metadata><dublinCore><dc:title>118 HR 1 IH: Lower Energy Costs Act</dc:title></dublinCore></metadata>
+<form>
+<congress>118th CONGRESS</congress>
+<legis-num>H. R. 1</legis-num>
+<official-title>To lower energy costs by increasing American energy production.</official-title>
+</form>
+<legis-body>
+<section id="H1" section-type="section-one"><enum>1.</enum><header>Short title</header><text display-inline="yes-display-inline">This Act may be cited as the <quote><short-title>Lower Energy Costs Act</short-title></quote>.</text></section>
+<section id="H2"><enum>2.</enum><header>Definitions</header><text>In this Act:</text>
+<paragraph id="H3"><enum>(1)</enum><header>Secretary</header><text>The term <term>Secretary</term> means the Secretary of Energy.</text></paragraph>
+<paragraph id="H4"><enum>(2)</enum><text>Section 3 of the Act is amended to read as follows:</text>
+<quoted-block style="OLC"><section id="H5"><enum>3.</enum><header>Quoted</header><text>Quoted text.</text></section></quoted-block></paragraph>
+</section>
+</legis-body>
+</bill>"#;
+
+#[test]
+fn parse_bill_dtd() -> Result<()> {
+    let document = BillDocument::parse(BILL_XML.as_bytes())?;
+
+    assert_eq!(
+        document.title.as_deref(),
+        Some("To lower energy costs by increasing American energy production.")
+    );
+    assert_eq!(document.sections.len(), 2);
+
+    let short_title = &document.sections[0];
+    assert_eq!(short_title.enumerator.as_deref(), Some("1."));
+    assert_eq!(short_title.heading.as_deref(), Some("Short title"));
+    assert_eq!(
+        short_title.text,
+        "This Act may be cited as the Lower Energy Costs Act."
+    );
+
+    let definitions = &document.sections[1];
+    assert_eq!(definitions.children.len(), 2);
+    assert_eq!(
+        definitions.children[0].text,
+        "The term Secretary means the Secretary of Energy."
+    );
+    assert!(definitions.children[1].children.is_empty());
+    assert!(definitions.children[1]
+        .text
+        .contains("3. Quoted Quoted text."));
+    assert_eq!(document.iter().count(), 4);
+    assert!(!document.text.contains("dc:title"));
+    assert!(document.text.contains("In this Act:"));
+
+    Ok(())
+}
+
+#[test]
+fn diff_versions() -> Result<()> {
+    let introduced = BillDocument::parse(
+        br#"<bill><legis-body>
+<section><enum>1.</enum><header>Short title</header><text>This Act may be cited as the Example Act.</text></section>
+<section><enum>2.</enum><header>Findings</header><text>Congress finds things.</text></section>
+<section><enum>3.</enum><header>Authorization</header><text>There are authorized $10.</text></section>
+</legis-body></bill>"#,
+    )?;
+    let enrolled = BillDocument::parse(
+        br#"<bill><legis-body>
+<section><enum>1.</enum><header>Short title</header><text>This Act may be cited as the Example Act.</text></section>
+<section><enum>2.</enum><header>Authorization</header><text>There are authorized $20.</text></section>
+<section><enum>3.</enum><header>Sunset</header><text>This Act expires in 2030.</text></section>
+</legis-body></bill>"#,
+    )?;
+
+    let diff = TextDiff::new(&introduced, &enrolled);
+    let kinds: Vec<_> = diff
+        .changes
+        .iter()
+        .map(|c| (c.kind, c.path.join(" ")))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (ChangeKind::Deleted, String::from("2. Findings")),
+            (ChangeKind::Modified, String::from("2. Authorization")),
+            (ChangeKind::Inserted, String::from("3. Sunset")),
+        ]
+    );
+    assert_eq!(diff.modified().count(), 1);
+
+    let unified = diff.unified();
+    assert!(unified.contains("-3. Authorization\n-There are authorized $10.\n"));
+    assert!(unified.contains("+There are authorized $20.\n"));
+
+    let json = serde_json::to_value(&diff).unwrap();
+    assert_eq!(json["changes"][1]["kind"], "modified");
+
+    Ok(())
+}
+
+#[test]
+fn diff_document_order() -> Result<()> {
+    let introduced = BillDocument::parse(
+        br#"<bill><legis-body>
+<section><enum>1.</enum><header>Short title</header><text>This Act may be cited as the Example Act.</text></section>
+<section><enum>2.</enum><header>Purpose</header><text>To do things.</text></section>
+<section><enum>3.</enum><header>Repeal</header><text>Section 5 is repealed.</text></section>
+</legis-body></bill>"#,
+    )?;
+    let enrolled = BillDocument::parse(
+        br#"<bill><legis-body>
+<section><enum>1.</enum><header>Short title</header><text>This Act may be cited as the Example Act.</text></section>
+<section><enum>1A.</enum><header>Definitions</header><text>In this Act, things are things.</text></section>
+<section><enum>2.</enum><header>Purpose</header><text>To do things.</text></section>
+</legis-body></bill>"#,
+    )?;
+
+    let diff = TextDiff::new(&introduced, &enrolled);
+    let kinds: Vec<_> = diff
+        .changes
+        .iter()
+        .map(|c| (c.kind, c.path.join(" ")))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (ChangeKind::Inserted, String::from("1A. Definitions")),
+            (ChangeKind::Deleted, String::from("3. Repeal")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diff_unlabeled_sections() -> Result<()> {
+    let introduced = BillDocument::parse(
+        br#"<bill><legis-body>
+<section><text>Congress finds the following.</text></section>
+<section><text>There are authorized $10.</text></section>
+</legis-body></bill>"#,
+    )?;
+    let enrolled = BillDocument::parse(
+        br#"<bill><legis-body>
+<section><text>Congress finds the following.</text></section>
+<section><text>There are authorized $20.</text></section>
+</legis-body></bill>"#,
+    )?;
+
+    let diff = TextDiff::new(&introduced, &enrolled);
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.changes[0].kind, ChangeKind::Modified);
+    assert_eq!(
+        diff.changes[0].new.as_deref(),
+        Some("There are authorized $20.")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_kinds() {
+    let xml: FormatKind = serde_json::from_str("\"Formatted XML\"").unwrap();
+    assert_eq!(xml, FormatKind::FormattedXml);
+
+    let unknown: FormatKind = serde_json::from_str("\"Generated HTML\"").unwrap();
+    assert_eq!(unknown, FormatKind::Other(String::from("Generated HTML")));
+    assert_eq!(unknown.to_string(), "Generated HTML");
+    assert_eq!(
+        serde_json::to_string(&unknown).unwrap(),
+        "\"Generated HTML\""
+    );
+}
+
+#[tokio::test]
+async fn download() -> Result<()> {
+    let api_key = std::env::var("CONGRESS_API_KEY").unwrap();
+    let client = ClientBuilder::new(api_key).build()?;
+    let text: TextResponse = client
+        .bill(118, BillType::House, 1)
+        .text()
+        .limit(1)
+        .send()
+        .await?;
+
+    for version in text.into_iter() {
+        let pdf = version.download(&client, FormatKind::Pdf).await?;
+        assert!(pdf.starts_with(b"%PDF"));
+        let document = version.document(&client).await?;
+        assert!(!document.sections.is_empty());
+    }
+
+    Ok(())
+}
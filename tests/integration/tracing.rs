@@ -0,0 +1,105 @@
+use congress::{
+    bill_type::BillType,
+    prelude::*,
+    testing::MockServer,
+    transport::{async_trait, HttpRequest, HttpResponse, HttpTransport},
+    ClientBuilder, Result,
+};
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+};
+use tracing_subscriber::{filter::LevelFilter, fmt::MakeWriter, util::SubscriberInitExt};
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Capture {
+    type Writer = Capture;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Answers with a 429 for the key `FIRST` and an empty bill list otherwise
+#[derive(Debug)]
+struct QuotaTransport;
+
+#[async_trait]
+impl HttpTransport for QuotaTransport {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let first = request.headers.iter().any(|(_, value)| value == "FIRST");
+        let (status, body) = match first {
+            true => (429, r#"{"error":{"code":"OVER_RATE_LIMIT"}}"#),
+            false => (200, r#"{"bills":[],"pagination":{"count":0}}"#),
+        };
+        Ok(HttpResponse {
+            status,
+            headers: vec![],
+            body: body.as_bytes().to_vec(),
+        })
+    }
+}
+
+fn capture() -> (Capture, tracing::subscriber::DefaultGuard) {
+    let capture = Capture::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(LevelFilter::DEBUG)
+        .with_writer(capture.clone())
+        .with_ansi(false)
+        .finish();
+    (capture, subscriber.set_default())
+}
+
+#[tokio::test]
+async fn spans() -> Result<()> {
+    let (capture, _guard) = capture();
+
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = server.client_builder_with_key("SECRET-KEY")?.build()?;
+    client
+        .bill(118, BillType::House, 3746)
+        .actions()
+        .limit(2)
+        .send()
+        .await?;
+
+    let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains("actions{congress=118 bill_type=hr bill_number=3746}"));
+    assert!(output.contains("endpoint=\"/v3/bill/118/hr/3746/actions\""));
+    assert!(output.contains("status=200"));
+    assert!(output.contains("attempts=1"));
+    assert!(!output.contains("SECRET-KEY"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attempts() -> Result<()> {
+    let (capture, _guard) = capture();
+
+    let client = ClientBuilder::new("FIRST")
+        .fallback_api_key("SECOND")
+        .transport(QuotaTransport)
+        .build()?;
+    client.bills().send().await?;
+
+    let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<&str> = output.lines().filter(|l| l.contains("response")).collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("attempts=1") && lines[0].contains("status=429"));
+    assert!(lines[1].contains("attempts=2") && lines[1].contains("status=200"));
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use congress::{
+    bill_type::BillType,
+    prelude::*,
+    transport::{async_trait, HttpRequest, HttpResponse, HttpTransport},
+    ClientBuilder, Result,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default, Clone)]
+struct FakeTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait]
+impl HttpTransport for FakeTransport {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.requests.lock().unwrap().push(request);
+        let body: serde_json::Value = super::fixture("v3_bill_118__format-json_limit-2.json");
+        Ok(HttpResponse {
+            status: 200,
+            headers: vec![],
+            body: body.to_string().into_bytes(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() -> Result<()> {
+    let transport = FakeTransport::default();
+    let client = ClientBuilder::new("KEY")
+        .transport(transport.clone())
+        .build()?;
+
+    let bills = client.bills().congress(118).limit(2).send().await?;
+    assert_eq!(
+        bills.bills[0].bill_type.to_string(),
+        BillType::House.to_string()
+    );
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.congress.gov/v3/bill/118?format=json&limit=2"
+    );
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|(name, value)| name == "X-Api-Key" && value == "KEY"));
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|(name, value)| name == "User-Agent" && value.starts_with("congress/")));
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use congress::{
+    chamber::Chamber,
+    version_code::{SummaryVersionCode, TextVersionCode},
+};
+
+#[test]
+fn text_version_codes() {
+    let enrolled: TextVersionCode = serde_json::from_str("\"Enrolled Bill\"").unwrap();
+    assert_eq!(enrolled, TextVersionCode::EnrolledBill);
+    assert_eq!("enr".parse(), Ok(TextVersionCode::EnrolledBill));
+    assert_eq!(serde_json::to_string(&enrolled).unwrap(), "\"ENR\"");
+
+    let mut versions = [
+        TextVersionCode::EnrolledBill,
+        TextVersionCode::EngrossedInHouse,
+        TextVersionCode::IntroducedInHouse,
+        TextVersionCode::ReportedInHouse,
+    ];
+    versions.sort();
+    assert_eq!(
+        versions.iter().map(|v| v.code()).collect::<Vec<_>>(),
+        vec!["IH", "RH", "EH", "ENR"]
+    );
+
+    assert_eq!(
+        TextVersionCode::PlacedOnCalendarSenate.chamber(),
+        Some(Chamber::Senate)
+    );
+    assert_eq!(TextVersionCode::PublicLaw.chamber(), None);
+    assert!("XYZ".parse::<TextVersionCode>().is_err());
+
+    let unknown: TextVersionCode = serde_json::from_str("\"Reported by Joint Committee\"").unwrap();
+    assert_eq!(
+        unknown,
+        TextVersionCode::Other(String::from("Reported by Joint Committee"))
+    );
+    assert_eq!(unknown.name(), None);
+    assert_eq!(unknown.chamber(), None);
+    assert!(unknown > TextVersionCode::PrivateLaw);
+    assert_eq!(
+        serde_json::to_string(&unknown).unwrap(),
+        "\"Reported by Joint Committee\""
+    );
+}
+
+#[test]
+fn summary_version_codes() {
+    let passed: SummaryVersionCode = serde_json::from_str("\"53\"").unwrap();
+    assert_eq!(passed, SummaryVersionCode::PassedHouse);
+    assert_eq!(passed.name(), Some("Passed House"));
+
+    let unknown: SummaryVersionCode = serde_json::from_str("\"99\"").unwrap();
+    assert_eq!(unknown, SummaryVersionCode::Other(String::from("99")));
+    assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"99\"");
+}
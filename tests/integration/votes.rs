@@ -0,0 +1,131 @@
+use congress::{
+    bill::actions::Action,
+    chamber::Chamber,
+    error::Error,
+    party::Party,
+    state::State,
+    votes::{RollCall, VotePosition},
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+const HOUSE_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rollcall-vote>
+<vote-metadata>
+<majority>R</majority>
+<congress>118</congress>
+<session>1st</session>
+<chamber>U.S. House of Representatives</chamber>
+<rollcall-num>243</rollcall-num>
+<legis-num>H R 3746</legis-num>
+<vote-question>On Passage</vote-question>
+<vote-type>YEA-AND-NAY</vote-type>
+<vote-result>Passed</vote-result>
+<action-date>31-May-2023</action-date>
+<action-time time-etz="20:38">8:38 PM</action-time>
+</vote-metadata>
+<vote-data>
+<recorded-vote><legislator name-id="A000370" sort-field="Adams" unaccented-name="Adams" party="D" state="NC" role="legislator">Adams</legislator><vote>Yea</vote></recorded-vote>
+<recorded-vote><legislator name-id="B001297" sort-field="Buck" unaccented-name="Buck" party="R" state="CO" role="legislator">Buck</legislator><vote>Nay</vote></recorded-vote>
+<recorded-vote><legislator name-id="C001120" sort-field="Crenshaw" unaccented-name="Crenshaw" party="R" state="TX" role="legislator">Crenshaw</legislator><vote>Not Voting</vote></recorded-vote>
+</vote-data>
+</rollcall-vote>"#;
+
+const SENATE_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<roll_call_vote>
+<congress>118</congress>
+<session>1</session>
+<congress_year>2023</congress_year>
+<vote_number>00146</vote_number>
+<vote_date>June 1, 2023, 09:05 PM</vote_date>
+<vote_question_text>On Passage of the Bill H.R. 3746</vote_question_text>
+<question>On Passage of the Bill</question>
+<vote_result>Bill Passed</vote_result>
+<members>
+<member><member_full>Baldwin (D-WI)</member_full><last_name>Baldwin</last_name><first_name>Tammy</first_name><party>D</party><state>WI</state><vote_cast>Yea</vote_cast><lis_member_id>S354</lis_member_id></member>
+<member><member_full>Lee (R-UT)</member_full><last_name>Lee</last_name><first_name>Mike</first_name><party>R</party><state>UT</state><vote_cast>Nay</vote_cast><lis_member_id>S346</lis_member_id></member>
+</members>
+</roll_call_vote>"#;
+
+#[test]
+fn recorded_votes() {
+    let action: Action = serde_json::from_value(json!({
+        "actionCode": "H37100",
+        "actionDate": "2023-05-31",
+        "sourceSystem": { "code": 2, "name": "House floor actions" },
+        "text": "On passage Passed by the Yeas and Nays: 314 - 117 (Roll no. 243).",
+        "type": "Floor",
+        "recordedVotes": [{
+            "chamber": "House",
+            "congress": 118,
+            "date": "2023-06-01T00:38:00Z",
+            "rollNumber": 243,
+            "sessionNumber": 1,
+            "url": "https://clerk.house.gov/evs/2023/roll243.xml",
+        }],
+    }))
+    .unwrap();
+
+    let votes = action.recorded_votes.unwrap();
+    assert_eq!(votes[0].roll_number, 243);
+    assert_eq!(votes[0].chamber, Chamber::House);
+}
+
+#[test]
+fn parse_house() -> congress::Result<()> {
+    let vote = RollCall::parse(Chamber::House, HOUSE_XML.as_bytes())?;
+
+    assert_eq!(vote.congress, 118);
+    assert_eq!(vote.session, 1);
+    assert_eq!(vote.roll_number, 243);
+    assert_eq!(vote.date, "2023-05-31".parse().ok());
+    assert_eq!(vote.result, "Passed");
+    assert_eq!(vote.position_of("B001297")?, Some(&VotePosition::Nay));
+    assert_eq!(vote.position_of("X000000")?, None);
+    assert_eq!(vote.positions[1].party, Party::Republican);
+    assert_eq!(vote.positions[1].state, State::Colorado);
+    assert_eq!(vote.count(&VotePosition::NotVoting), 1);
+
+    Ok(())
+}
+
+#[test]
+fn parse_senate() -> congress::Result<()> {
+    let vote = RollCall::parse(Chamber::Senate, SENATE_XML.as_bytes())?;
+
+    assert_eq!(vote.roll_number, 146);
+    assert_eq!(vote.date, "2023-06-01".parse().ok());
+    assert_eq!(vote.question, "On Passage of the Bill");
+    assert_eq!(
+        vote.position_by_name("lee", State::Utah),
+        Some(&VotePosition::Nay)
+    );
+    assert_eq!(vote.positions[0].lis_member_id.as_deref(), Some("S354"));
+
+    Ok(())
+}
+
+#[test]
+fn senate_bioguide_ids() -> congress::Result<()> {
+    let mut vote = RollCall::parse(Chamber::Senate, SENATE_XML.as_bytes())?;
+    assert!(matches!(
+        vote.position_of("L000577"),
+        Err(Error::MissingBioguideId {
+            chamber: Chamber::Senate,
+            roll_number: 146
+        })
+    ));
+
+    let ids = HashMap::from([("S346", "L000577")]);
+    let missing = vote.set_bioguide_ids(|lis| ids.get(lis).map(|id| id.to_string()));
+    assert_eq!(missing, 1);
+    assert_eq!(vote.position_of("L000577")?, Some(&VotePosition::Nay));
+    assert!(vote.position_of("B001230").is_err());
+
+    vote.set_bioguide_ids(|lis| (lis == "S354").then(|| "B001230".to_string()));
+    assert_eq!(vote.position_of("L000577")?, Some(&VotePosition::Nay));
+    assert_eq!(vote.position_of("B001230")?, Some(&VotePosition::Yea));
+    assert_eq!(vote.position_of("X000000")?, None);
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use super::fixture;
+use congress::{
+    bill_id::BillId,
+    bill_type::BillType,
+    transport::{async_trait, HttpRequest, HttpResponse, HttpTransport},
+    version_code::TextVersionCode,
+    watch::{Event, Watcher},
+    Client, ClientBuilder, Result,
+};
+use serde_json::{json, Value};
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+/// Answers each path with its responses in turn, repeating the last one
+#[derive(Debug, Default)]
+struct Timeline(Mutex<HashMap<String, Vec<Value>>>);
+
+#[async_trait]
+impl HttpTransport for Timeline {
+    async fn get(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut responses = self.0.lock().unwrap();
+        let bodies = responses.get_mut(request.url.path()).unwrap();
+        let body = match bodies.len() {
+            1 => bodies[0].clone(),
+            _ => bodies.remove(0),
+        };
+        Ok(HttpResponse {
+            status: 200,
+            headers: vec![],
+            body: body.to_string().into_bytes(),
+        })
+    }
+}
+
+fn last_page(items: &str, list: Vec<Value>) -> Value {
+    json!({ items: list, "pagination": { "count": list.len() } })
+}
+
+/// H.R. 3746 before and after it gains two actions, a text version and a law.
+/// Its one cosponsor is left out of both snapshots.
+fn client() -> Client {
+    let mut after: Value = fixture("v3_bill_118_hr_3746__format-json.json");
+    let mut before = after.clone();
+    before["bill"]["actions"]["count"] = json!(2);
+    before["bill"]["textVersions"]["count"] = json!(1);
+    before["bill"]["updateDate"] = json!("2023-05-31T12:00:00Z");
+    before["bill"]["updateDateIncludingText"] = json!("2023-05-31T12:00:00Z");
+    before["bill"].as_object_mut().unwrap().remove("laws");
+    after["bill"]["actions"]["count"] = json!(4);
+    after["bill"]["textVersions"]["count"] = json!(2);
+
+    let first: Value = fixture("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    let second: Value = fixture("v3_bill_118_hr_3746_actions__format-json_limit-2_offset-2.json");
+    let actions = |page: &Value| page["actions"].as_array().unwrap().clone();
+    let older_actions = actions(&second);
+    let all_actions = [actions(&first), actions(&second)].concat();
+
+    let text: Value = fixture("v3_bill_118_hr_3746_text__format-json.json");
+    let versions = text["textVersions"].as_array().unwrap();
+    let older_versions = versions[versions.len() - 1..].to_vec();
+    let newer_versions = versions[versions.len() - 2..].to_vec();
+
+    let responses = HashMap::from([
+        ("/v3/bill/118/hr/3746".to_string(), vec![before, after]),
+        (
+            "/v3/bill/118/hr/3746/actions".to_string(),
+            vec![
+                last_page("actions", older_actions),
+                last_page("actions", all_actions),
+            ],
+        ),
+        (
+            "/v3/bill/118/hr/3746/cosponsors".to_string(),
+            vec![last_page("cosponsors", vec![])],
+        ),
+        (
+            "/v3/bill/118/hr/3746/text".to_string(),
+            vec![
+                last_page("textVersions", older_versions),
+                last_page("textVersions", newer_versions),
+            ],
+        ),
+    ]);
+
+    ClientBuilder::new("KEY")
+        .transport(Timeline(Mutex::new(responses)))
+        .build()
+        .unwrap()
+}
+
+const HR_3746: BillId = BillId {
+    congress: 118,
+    bill_type: BillType::House,
+    number: 3746,
+};
+
+fn assert_changes(events: &[Event]) {
+    assert_eq!(events.len(), 4);
+    assert!(events.iter().all(|e| e.bill() == HR_3746));
+    let actions: Vec<&str> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::NewAction { action, .. } => Some(action.text.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(actions.len(), 2);
+    let law = TextVersionCode::PublicLaw;
+    assert!(events.iter().any(
+        |e| matches!(e, Event::NewTextVersion { version, .. } if version.version_code == law)
+    ));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::BecameLaw { law, .. } if law.number == "118-5")));
+}
+
+#[tokio::test]
+async fn poll_bill() -> Result<()> {
+    let client = client();
+    let mut watcher = Watcher::new(&client, [HR_3746]);
+
+    assert_eq!(watcher.poll_bill(HR_3746).await?, vec![]);
+    assert_changes(&watcher.poll_bill(HR_3746).await?);
+    assert_eq!(watcher.poll_bill(HR_3746).await?, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn run() -> Result<()> {
+    let client = client();
+    let watcher = Watcher::new(&client, [HR_3746]);
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(8);
+
+    let watching = watcher.run(Duration::from_millis(1), sender);
+    let receiving = async {
+        let mut events = vec![];
+        while events.len() < 4 {
+            events.push(receiver.recv().await.unwrap());
+        }
+        drop(receiver);
+        events
+    };
+    let (result, events) = tokio::join!(watching, receiving);
+    result?;
+    assert_changes(&events);
+
+    let json = serde_json::to_value(&events[0]).unwrap();
+    assert_eq!(json["event"], "new_action");
+    assert_eq!(json["bill"]["billType"], "HR");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cosponsors() -> Result<()> {
+    let mut after: Value = fixture("v3_bill_118_hr_3746__format-json.json");
+    let mut before = after.clone();
+    after["bill"]["cosponsors"]["countIncludingWithdrawnCosponsors"] = json!(2);
+    before["bill"]["updateDate"] = json!("2023-05-31T12:00:00Z");
+
+    let original = fixture::<Value>("v3_bill_118_hr_3746_cosponsors__format-json.json")
+        ["cosponsors"][0]
+        .clone();
+    let mut withdrawn = original.clone();
+    withdrawn["sponsorshipWithdrawnDate"] = json!("2023-06-02");
+    let mut added = original.clone();
+    added["bioguideId"] = json!("N000001");
+    added["sponsorshipDate"] = json!("2023-06-02");
+
+    let actions: Value = fixture("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    let text: Value = fixture("v3_bill_118_hr_3746_text__format-json.json");
+    let responses = HashMap::from([
+        ("/v3/bill/118/hr/3746".to_string(), vec![before, after]),
+        (
+            "/v3/bill/118/hr/3746/actions".to_string(),
+            vec![last_page(
+                "actions",
+                actions["actions"].as_array().unwrap().clone(),
+            )],
+        ),
+        (
+            "/v3/bill/118/hr/3746/cosponsors".to_string(),
+            vec![
+                last_page("cosponsors", vec![original]),
+                last_page("cosponsors", vec![withdrawn, added]),
+            ],
+        ),
+        (
+            "/v3/bill/118/hr/3746/text".to_string(),
+            vec![last_page(
+                "textVersions",
+                text["textVersions"].as_array().unwrap().clone(),
+            )],
+        ),
+    ]);
+    let client = ClientBuilder::new("KEY")
+        .transport(Timeline(Mutex::new(responses)))
+        .build()?;
+
+    let mut watcher = Watcher::new(&client, [HR_3746]);
+    assert_eq!(watcher.poll_bill(HR_3746).await?, vec![]);
+    let events = watcher.poll_bill(HR_3746).await?;
+    assert_eq!(events.len(), 2);
+    match &events[0] {
+        Event::CosponsorAdded { cosponsor, .. } => assert_eq!(cosponsor.bioguide_id, "N000001"),
+        e => panic!("unexpected event {e:?}"),
+    }
+    match &events[1] {
+        Event::CosponsorWithdrawn { cosponsor, .. } => {
+            assert_eq!(cosponsor.bioguide_id, "G000583");
+            assert!(cosponsor.sponsorship_withdrawn_date.is_some());
+        }
+        e => panic!("unexpected event {e:?}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use congress::{
+    bill::actions::ActionsResponse,
+    bill_id::BillId,
+    bill_type::BillType,
+    error::Error,
+    watch::Event,
+    webhook::{sign, verify, Webhook, SIGNATURE_HEADER},
+    Result,
+};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use url::Url;
+
+const SECRET: &[u8] = b"webhook secret";
+
+/// A delivery as the receiver saw it
+#[derive(Debug)]
+struct Delivery {
+    signature: Option<String>,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+/// Starts a local receiver answering with `status`, returning its URL and the deliveries
+fn receiver(status: u16) -> (Url, mpsc::UnboundedReceiver<Delivery>) {
+    receiver_answering(&[status])
+}
+
+/// Starts a local receiver answering with each of `statuses` in turn, then
+/// with the last one for every later delivery
+fn receiver_answering(statuses: &[u16]) -> (Url, mpsc::UnboundedReceiver<Delivery>) {
+    let statuses = Arc::new(statuses.to_vec());
+    let count = Arc::new(AtomicUsize::new(0));
+    let (sender, deliveries) = mpsc::unbounded_channel();
+    let make = make_service_fn(move |_| {
+        let sender = sender.clone();
+        let statuses = statuses.clone();
+        let count = count.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                let sender = sender.clone();
+                let n = count.fetch_add(1, Ordering::SeqCst);
+                let status = statuses[n.min(statuses.len() - 1)];
+                async move {
+                    let header = |name| {
+                        request
+                            .headers()
+                            .get(name)
+                            .map(|v: &hyper::header::HeaderValue| v.to_str().unwrap().to_string())
+                    };
+                    let signature = header(SIGNATURE_HEADER);
+                    let content_type = header("content-type");
+                    let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+                    sender
+                        .send(Delivery {
+                            signature,
+                            content_type,
+                            body: body.to_vec(),
+                        })
+                        .unwrap();
+                    let response = Response::builder().status(status).body(Body::empty());
+                    Ok::<_, Infallible>(response.unwrap())
+                }
+            }))
+        }
+    });
+    let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make);
+    let url = Url::parse(&format!("http://{}/hook", server.local_addr())).unwrap();
+    tokio::spawn(server);
+    (url, deliveries)
+}
+
+fn events() -> Vec<Event> {
+    let actions: ActionsResponse =
+        super::fixture("v3_bill_118_hr_3746_actions__format-json_limit-2.json");
+    actions
+        .actions
+        .into_iter()
+        .map(|action| Event::NewAction {
+            bill: BillId::new(118, BillType::House, 3746),
+            action,
+        })
+        .collect()
+}
+
+#[test]
+fn signatures() {
+    let signature = sign(SECRET, b"{}");
+    assert!(signature.starts_with("sha256="));
+    assert_eq!(signature.len(), "sha256=".len() + 64);
+    assert!(verify(SECRET, b"{}", &signature));
+    assert!(!verify(SECRET, b"{ }", &signature));
+    assert!(!verify(b"another secret", b"{}", &signature));
+    assert!(!verify(
+        SECRET,
+        b"{}",
+        signature.trim_start_matches("sha256=")
+    ));
+    assert!(!verify(SECRET, b"{}", "sha256=zz"));
+}
+
+#[tokio::test]
+async fn deliver() -> Result<()> {
+    let (url, mut deliveries) = receiver(204);
+    let webhook = Webhook::new(url, SECRET);
+    webhook.deliver(&events()).await?;
+
+    let delivery = deliveries.recv().await.unwrap();
+    assert_eq!(delivery.content_type.as_deref(), Some("application/json"));
+    assert!(verify(SECRET, &delivery.body, &delivery.signature.unwrap()));
+
+    let payload: serde_json::Value = serde_json::from_slice(&delivery.body).unwrap();
+    assert!(payload["sent_at"].is_string());
+    let events = payload["events"].as_array().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["event"], "new_action");
+    assert_eq!(events[0]["bill"]["number"], 3746);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejected() {
+    let (url, _deliveries) = receiver(500);
+    let webhook = Webhook::new(url, SECRET);
+    match webhook.deliver(&events()).await {
+        Err(Error::UnexpectedStatus { status: 500, .. }) => {}
+        result => panic!("unexpected result {result:?}"),
+    }
+}
+
+#[tokio::test]
+async fn forward() -> Result<()> {
+    let (url, mut deliveries) = receiver(200);
+    let webhook = Webhook::new(url, SECRET);
+    let (sender, receiver) = mpsc::channel(8);
+    for event in events() {
+        sender.send(event).await.unwrap();
+    }
+    drop(sender);
+    webhook
+        .forward(receiver, |failed| panic!("{:?}", failed.error))
+        .await;
+
+    let delivery = deliveries.recv().await.unwrap();
+    let payload: serde_json::Value = serde_json::from_slice(&delivery.body).unwrap();
+    assert_eq!(payload["events"].as_array().unwrap().len(), 2);
+    assert!(deliveries.try_recv().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forward_retries() -> Result<()> {
+    let (url, mut deliveries) = receiver_answering(&[503, 200]);
+    let webhook = Webhook::new(url, SECRET).retry(3, Duration::from_millis(10));
+    let (sender, receiver) = mpsc::channel(8);
+    for event in events() {
+        sender.send(event).await.unwrap();
+    }
+    drop(sender);
+    webhook
+        .forward(receiver, |failed| panic!("{:?}", failed.error))
+        .await;
+
+    let failed = deliveries.recv().await.unwrap();
+    let retried = deliveries.recv().await.unwrap();
+    assert_eq!(failed.body, retried.body);
+    assert!(deliveries.try_recv().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forward_keeps_going() -> Result<()> {
+    let (url, mut deliveries) = receiver_answering(&[500, 500, 200]);
+    let webhook = Webhook::new(url, SECRET).retry(1, Duration::from_millis(10));
+    let (sender, receiver) = mpsc::channel(8);
+    let (failures, mut failed) = mpsc::unbounded_channel();
+    let forwarding = tokio::spawn(async move {
+        webhook
+            .forward(receiver, |delivery| failures.send(delivery).unwrap())
+            .await
+    });
+
+    let mut events = events().into_iter();
+    sender.send(events.next().unwrap()).await.unwrap();
+    deliveries.recv().await.unwrap();
+    deliveries.recv().await.unwrap();
+
+    // The first event is reported after its retry, and the next one still goes out
+    let failure = failed.recv().await.unwrap();
+    assert_eq!(failure.events.len(), 1);
+    assert!(matches!(
+        failure.error,
+        Error::UnexpectedStatus { status: 500, .. }
+    ));
+    sender.send(events.next().unwrap()).await.unwrap();
+    let delivery = deliveries.recv().await.unwrap();
+    let payload: serde_json::Value = serde_json::from_slice(&delivery.body).unwrap();
+    assert_eq!(payload["events"].as_array().unwrap().len(), 1);
+
+    drop(sender);
+    forwarding.await.unwrap();
+    assert!(deliveries.try_recv().is_err());
+    assert!(failed.try_recv().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use congress::{bill_type::BillType, testing::MockServer, Result};
+
+#[tokio::test]
+async fn raw_xml() -> Result<()> {
+    let server = MockServer::replay("tests/fixtures").await?;
+    let client = server.client_builder()?.build()?;
+
+    let xml = client.bill(118, BillType::House, 3746).send_xml().await?;
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("<title>Fiscal Responsibility Act of 2023</title>"));
+
+    Ok(())
+}